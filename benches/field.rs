@@ -0,0 +1,86 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use reed_solomon::field::GaloisField;
+use reed_solomon::utils;
+
+static FIELD: GaloisField = reed_solomon::galois_field!();
+
+fn elements(len: usize, seed: i128) -> Vec<reed_solomon::field_element::FieldElement<'static>> {
+    utils::nums_to_elements((0..len as i128).map(|i| i * 2654435761 + seed), &FIELD)
+}
+
+fn element_ops(c: &mut Criterion) {
+    let lhs = FIELD.new_element(1238909831);
+    let rhs = FIELD.new_element(2110495054);
+
+    c.bench_function("field/mul", |b| b.iter(|| black_box(lhs) * black_box(rhs)));
+    c.bench_function("field/add", |b| b.iter(|| black_box(lhs) + black_box(rhs)));
+    c.bench_function("field/inverse", |b| b.iter(|| black_box(lhs).inverse()));
+    c.bench_function("field/pow", |b| {
+        b.iter(|| black_box(lhs).pow(black_box(3221225471)))
+    });
+}
+
+fn reduction(c: &mut Criterion) {
+    let modulus = FIELD.k_modulus;
+    let inputs = (0..1024u128)
+        .map(|i| (i * 2654435761 + 17) % modulus as u128 * ((i * 40503 + 42) % modulus as u128))
+        .collect::<Vec<u128>>();
+
+    c.bench_function("field/reduce_mod", |b| {
+        b.iter(|| {
+            black_box(&inputs)
+                .iter()
+                .map(|&x| (x % black_box(modulus) as u128) as u64)
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("field/reduce_barrett", |b| {
+        b.iter(|| {
+            black_box(&inputs)
+                .iter()
+                .map(|&x| FIELD.barrett_reduce(x))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+fn batch_ops(c: &mut Criterion) {
+    let lhs = elements(1_000_000, 17);
+    let rhs = elements(1_000_000, 42);
+
+    c.bench_function("field/mul_scalar_1e6", |b| {
+        b.iter(|| {
+            lhs.iter()
+                .zip(rhs.iter())
+                .map(|(&l, &r)| l * r)
+                .collect::<Vec<_>>()
+        })
+    });
+    #[cfg(feature = "simd")]
+    c.bench_function("field/mul_batch_1e6", |b| {
+        b.iter(|| FIELD.mul_batch(black_box(&lhs), black_box(&rhs)))
+    });
+
+    let elems = elements(10_000, 5);
+    c.bench_function("field/pow_each_1e4", |b| {
+        b.iter(|| {
+            elems
+                .iter()
+                .map(|el| el.pow(3221225471))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function("field/pow_batch_1e4", |b| {
+        b.iter(|| FIELD.pow_batch(black_box(&elems), 3221225471))
+    });
+    c.bench_function("field/bit_reverse_permute_2^16", |b| {
+        b.iter_batched(
+            || elements(1 << 16, 1),
+            |mut elems| utils::bit_reverse_permute(&mut elems),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, element_ops, reduction, batch_ops);
+criterion_main!(benches);
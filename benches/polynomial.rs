@@ -0,0 +1,148 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use reed_solomon::field::GaloisField;
+use reed_solomon::field_element::FieldElement;
+use reed_solomon::ntt;
+use reed_solomon::polynomial::Polynomial;
+use reed_solomon::utils;
+
+static FIELD: GaloisField = reed_solomon::galois_field!();
+
+fn poly(len: usize, seed: i128) -> Polynomial<'static> {
+    let coeffs = utils::nums_to_elements((0..len as i128).map(|i| i * 2654435761 + seed), &FIELD);
+    Polynomial::new(coeffs, &FIELD, "x")
+}
+
+/// Multiplies polynomials by pointwise product of their transforms.
+fn ntt_mul(
+    lhs: &[FieldElement<'static>],
+    rhs: &[FieldElement<'static>],
+) -> Vec<FieldElement<'static>> {
+    let len = (lhs.len() + rhs.len()).next_power_of_two();
+    let mut lhs = lhs.to_vec();
+    let mut rhs = rhs.to_vec();
+    lhs.resize(len, FIELD.zero());
+    rhs.resize(len, FIELD.zero());
+
+    ntt::ntt(&mut lhs);
+    ntt::ntt(&mut rhs);
+    for (l, &r) in lhs.iter_mut().zip(rhs.iter()) {
+        *l *= r;
+    }
+    ntt::intt(&mut lhs);
+
+    lhs
+}
+
+fn multiplication(c: &mut Criterion) {
+    let mut group = c.benchmark_group("polynomial/mul");
+
+    for len in [16, 64, 256, 1024] {
+        let (lhs, rhs) = (poly(len, 3), poly(len, 7));
+
+        group.bench_with_input(BenchmarkId::new("schoolbook", len), &len, |b, _| {
+            b.iter(|| black_box(lhs.clone()) * black_box(rhs.clone()))
+        });
+        group.bench_with_input(BenchmarkId::new("ntt", len), &len, |b, _| {
+            b.iter(|| ntt_mul(black_box(lhs.as_ref()), black_box(rhs.as_ref())))
+        });
+    }
+
+    group.finish();
+}
+
+fn transform(c: &mut Criterion) {
+    let mut group = c.benchmark_group("polynomial/ntt");
+    group.sample_size(10);
+
+    for len in [1 << 12, 1 << 20] {
+        let values = utils::nums_to_elements((0..len as i128).map(|i| i * 2654435761 + 5), &FIELD);
+
+        group.bench_with_input(BenchmarkId::new("ntt", len), &len, |b, _| {
+            b.iter_batched(
+                || values.clone(),
+                |mut values| ntt::ntt(black_box(&mut values)),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+fn division_and_evaluation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("polynomial");
+
+    for len in [64, 256, 1024] {
+        let (lhs, rhs) = (poly(2 * len, 11), poly(len, 13));
+        let x = FIELD.new_element(1039791324);
+
+        group.bench_with_input(BenchmarkId::new("div_rem", len), &len, |b, _| {
+            b.iter(|| black_box(&lhs).div_rem(black_box(&rhs)))
+        });
+        group.bench_with_input(BenchmarkId::new("evaluate", len), &len, |b, _| {
+            b.iter(|| black_box(&lhs).evaluate(black_box(x)))
+        });
+        group.bench_with_input(BenchmarkId::new("synthetic_div", len), &len, |b, _| {
+            b.iter(|| black_box(&lhs).synthetic_div(black_box(x)))
+        });
+    }
+
+    group.finish();
+}
+
+fn evaluation_of_many(c: &mut Criterion) {
+    let mut group = c.benchmark_group("polynomial/evaluate_many");
+    let x = FIELD.new_element(1039791324);
+
+    for count in [16, 256] {
+        let polys = (0..count).map(|i| poly(256, i)).collect::<Vec<_>>();
+        let poly_refs = polys.iter().collect::<Vec<&Polynomial>>();
+
+        group.bench_with_input(
+            BenchmarkId::new("evaluate_many_at", count),
+            &count,
+            |b, _| b.iter(|| Polynomial::evaluate_many_at(black_box(&poly_refs), black_box(x))),
+        );
+        group.bench_with_input(BenchmarkId::new("evaluate_each", count), &count, |b, _| {
+            b.iter(|| {
+                black_box(&polys)
+                    .iter()
+                    .map(|poly| poly.evaluate(black_box(x)))
+                    .collect::<Vec<_>>()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn modular_composition(c: &mut Criterion) {
+    let mut group = c.benchmark_group("polynomial/modular_compose");
+
+    for len in [16, 64] {
+        let (f, g, h) = (poly(len, 17), poly(len, 19), poly(len, 23));
+
+        group.bench_with_input(BenchmarkId::new("brent_kung", len), &len, |b, _| {
+            b.iter(|| black_box(&f).modular_compose(black_box(&g), black_box(&h)))
+        });
+        group.bench_with_input(BenchmarkId::new("naive", len), &len, |b, _| {
+            b.iter(|| {
+                black_box(&f)
+                    .compose(black_box(g.clone()))
+                    .div_rem(black_box(&h))
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    multiplication,
+    transform,
+    division_and_evaluation,
+    evaluation_of_many,
+    modular_composition
+);
+criterion_main!(benches);
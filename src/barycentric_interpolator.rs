@@ -0,0 +1,168 @@
+use crate::error::RsError;
+use crate::field::GaloisField;
+use crate::field_element::FieldElement;
+use crate::polynomial::Polynomial;
+
+use std::collections::HashSet;
+
+/// Interpolator in barycentric form over the fixed set of nodes `x_points`.
+/// Barycentric weights w_j = 1 / prod(x_j - x_m), m != j are calculated once,
+/// so repeated interpolation of different values over the same nodes is cheap.
+#[derive(Debug, Clone)]
+pub struct BarycentricInterpolator<'a> {
+    x_points: Vec<FieldElement<'a>>,
+    weights: Vec<FieldElement<'a>>,
+    field: &'a GaloisField,
+}
+
+impl<'a> BarycentricInterpolator<'a> {
+    pub fn new(x_points: Vec<FieldElement<'a>>) -> BarycentricInterpolator<'a> {
+        let field = match x_points.first() {
+            Some(el) => el.field,
+            None => panic!("Impossible to build interpolator over the empty set of nodes."),
+        };
+
+        let mut points = HashSet::with_capacity(x_points.len());
+        for el in x_points.iter() {
+            if el.field as *const _ != field as *const _ {
+                panic!("Not all elements are lay in the same field.");
+            }
+            if !points.insert(el.canonical_val()) {
+                panic!(
+                    "Impossible to build interpolator: {}.",
+                    RsError::DuplicateInterpolationPoint
+                );
+            }
+        }
+
+        let weights = x_points
+            .iter()
+            .enumerate()
+            .map(|(j, &x_j)| {
+                x_points
+                    .iter()
+                    .enumerate()
+                    .filter(|&(m, _)| m != j)
+                    .fold(field.one(), |acc, (_, &x_m)| acc * (x_j - x_m))
+                    .inverse()
+            })
+            .collect();
+
+        BarycentricInterpolator {
+            x_points,
+            weights,
+            field,
+        }
+    }
+
+    /// Builds polynomial p such that p(x_j) = y_j for all nodes x_j.
+    pub fn interpolate(&self, y: &[FieldElement<'a>]) -> Polynomial<'a> {
+        self.check_values(y);
+
+        // l(x) = prod(x - x_m) over all nodes
+        let mut node_poly = vec![self.field.one()];
+        for &x_m in self.x_points.iter() {
+            let mut next = vec![self.field.zero(); node_poly.len() + 1];
+            for (i, &coef) in node_poly.iter().enumerate() {
+                next[i] -= coef * x_m;
+                next[i + 1] += coef;
+            }
+            node_poly = next;
+        }
+
+        let mut res = vec![self.field.zero(); self.x_points.len()];
+        for ((&x_j, &w_j), &y_j) in self.x_points.iter().zip(self.weights.iter()).zip(y.iter()) {
+            // l(x) / (x - x_j) by Ruffini's rule
+            let scale = w_j * y_j;
+            let mut carry = self.field.zero();
+            for i in (0..res.len()).rev() {
+                carry = node_poly[i + 1] + carry * x_j;
+                res[i] += scale * carry;
+            }
+        }
+
+        Polynomial::new(res, self.field, "x")
+    }
+
+    /// Evaluates interpolating polynomial at point `x`
+    /// without building the polynomial itself.
+    pub fn evaluate_at(&self, y: &[FieldElement<'a>], x: FieldElement<'a>) -> FieldElement<'a> {
+        self.check_values(y);
+
+        let mut numerator = self.field.zero();
+        let mut denominator = self.field.zero();
+
+        for ((&x_j, &w_j), &y_j) in self.x_points.iter().zip(self.weights.iter()).zip(y.iter()) {
+            if x == x_j {
+                return y_j;
+            }
+
+            let term = w_j / (x - x_j);
+            numerator += term * y_j;
+            denominator += term;
+        }
+
+        numerator / denominator
+    }
+
+    fn check_values(&self, y: &[FieldElement<'a>]) {
+        if y.len() != self.x_points.len() {
+            panic!("Impossible to establish a one-to-one correspondence between the definition and value areas.");
+        }
+
+        for el in y.iter() {
+            if el.field as *const _ != self.field as *const _ {
+                panic!("Not all elements are lay in the same field.");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BarycentricInterpolator;
+    use crate::field::GaloisField;
+    use crate::polynomial::Polynomial;
+    use crate::utils;
+
+    static FIELD: GaloisField = crate::galois_field!();
+
+    #[test]
+    fn interpolate_test() {
+        let x = utils::nums_to_elements(vec![1, 3, 7, 12, 20], &FIELD);
+        let y_data = [
+            vec![5, 8, 13, 21, 34],
+            vec![-1, 0, 1, 0, -1],
+            vec![100, 200, 300, 400, 500],
+            vec![3217314898i64, 1575806093, 503069339, 2848844329, 2969028563],
+        ];
+        let interpolator = BarycentricInterpolator::new(x.clone());
+
+        for y_raw in y_data {
+            let y = utils::nums_to_elements(y_raw, &FIELD);
+            assert_eq!(
+                interpolator.interpolate(&y),
+                Polynomial::interpolate_slices(&x, &y)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolation points are not distinct")]
+    fn duplicate_points_test() {
+        BarycentricInterpolator::new(utils::nums_to_elements(vec![1, 2, 1], &FIELD));
+    }
+
+    #[test]
+    fn evaluate_at_test() {
+        let x = utils::nums_to_elements(vec![2, 4, 5, 9], &FIELD);
+        let y = utils::nums_to_elements(vec![17, -6, 42, 1039791324], &FIELD);
+        let interpolator = BarycentricInterpolator::new(x.clone());
+        let poly = Polynomial::interpolate_slices(&x, &y);
+
+        for point in [0, 2, 3, 9, 11, -1712893553] {
+            let point = FIELD.new_element(point);
+            assert_eq!(interpolator.evaluate_at(&y, point), poly.evaluate(point));
+        }
+    }
+}
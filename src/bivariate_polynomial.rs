@@ -0,0 +1,458 @@
+use crate::field::GaloisField;
+use crate::field_element::FieldElement;
+use crate::polynomial::Polynomial;
+
+use std::{cmp, ops};
+
+/// Polynomial in two variables x and y above some finite field `field`,
+/// stored as a polynomial in y whose coefficients are polynomials in x.
+/// # Arguments
+/// * `coeffs` - polynomials in x that are the coefficients of the powers of y,
+/// listed in ascending order of y powers
+/// * `field` - some finite field that provides polynomial coefficients
+#[derive(Debug, Clone)]
+pub struct BivariatePolynomial<'a> {
+    coeffs: Vec<Polynomial<'a>>,
+    field: &'a GaloisField,
+}
+
+impl<'a> BivariatePolynomial<'a> {
+    pub fn new(mut coeffs: Vec<Polynomial<'a>>, field: &'a GaloisField) -> BivariatePolynomial<'a> {
+        for coef in coeffs.iter() {
+            if !std::ptr::eq(coef.field(), field) {
+                panic!(
+                    "Not all coefficients of the constructing
+                polynomial are lay in the same field!"
+                );
+            }
+        }
+
+        let len = coeffs
+            .iter()
+            .rposition(|coef| coef.non_empty())
+            .map_or(0, |pos| pos + 1);
+        coeffs.truncate(len);
+
+        BivariatePolynomial { coeffs, field }
+    }
+
+    /// Builds polynomial from its terms given as `(x power, y power, coefficient)`,
+    /// coefficients of the same term are summed.
+    pub fn from_terms(
+        terms: Vec<(usize, usize, FieldElement<'a>)>,
+        field: &'a GaloisField,
+    ) -> BivariatePolynomial<'a> {
+        let y_len = terms.iter().map(|&(_, j, _)| j + 1).max().unwrap_or(0);
+        let mut coeffs = vec![vec![]; y_len];
+
+        for (i, j, coef) in terms {
+            if coeffs[j].len() <= i {
+                coeffs[j].resize(i + 1, field.zero());
+            }
+            coeffs[j][i] += coef;
+        }
+
+        BivariatePolynomial::new(
+            coeffs
+                .into_iter()
+                .map(|coef| Polynomial::new(coef, field, "x"))
+                .collect(),
+            field,
+        )
+    }
+
+    pub fn field(&self) -> &'a GaloisField {
+        self.field
+    }
+
+    /// Coefficients of the powers of y listed in ascending order.
+    pub fn coeffs(&self) -> &[Polynomial<'a>] {
+        &self.coeffs
+    }
+
+    pub fn deg_x(&self) -> i64 {
+        self.coeffs
+            .iter()
+            .map(|coef| coef.deg())
+            .max()
+            .unwrap_or(-1)
+    }
+
+    pub fn deg_y(&self) -> i64 {
+        self.coeffs.len() as i64 - 1
+    }
+
+    /// (wx, wy)-weighted degree: maximum of wx * i + wy * j over the non-zero terms x^i * y^j,
+    /// -1 for the zero polynomial.
+    pub fn weighted_deg(&self, wx: usize, wy: usize) -> i64 {
+        self.coeffs
+            .iter()
+            .enumerate()
+            .filter_map(|(j, coef)| coef.degree().map(|i| (wx * i + wy * j) as i64))
+            .max()
+            .unwrap_or(-1)
+    }
+
+    /// Substitutes polynomial `y` in place of y, Q(x, y(x)), using Horner's scheme.
+    pub fn evaluate_y(&self, y: &Polynomial<'a>) -> Polynomial<'a> {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(Polynomial::new(vec![], self.field, "x"), |acc, coef| {
+                acc * y.clone() + coef.clone()
+            })
+    }
+
+    /// Finds all polynomials f of degree at most `max_deg` such that Q(x, f(x)) = 0,
+    /// i.e. the factors y - f(x) of Q, using Roth–Ruckenstein algorithm: coefficients of f
+    /// are found one by one as the roots of Q(0, y), followed by the substitution
+    /// Q(x, x * y + root) divided by the greatest power of x.
+    pub fn y_roots(&self, max_deg: usize) -> Vec<Polynomial<'a>> {
+        if self.coeffs.is_empty() {
+            panic!("Impossible to find y-roots of the zero polynomial cause any polynomial is its root.");
+        }
+
+        let mut roots = vec![];
+        self.y_roots_(&mut vec![], max_deg, &mut roots);
+        roots
+    }
+
+    fn y_roots_(
+        &self,
+        prefix: &mut Vec<FieldElement<'a>>,
+        max_deg: usize,
+        roots: &mut Vec<Polynomial<'a>>,
+    ) {
+        let x_valuation = self
+            .coeffs
+            .iter()
+            .filter_map(|coef| {
+                coef.as_ref()
+                    .iter()
+                    .position(|el| el.canonical_val() != self.field.zero)
+            })
+            .min()
+            .unwrap();
+        let reduced = BivariatePolynomial::new(
+            self.coeffs
+                .iter()
+                .map(|coef| {
+                    let coeffs = coef.as_ref().get(x_valuation..).unwrap_or(&[]);
+                    Polynomial::new(coeffs.to_vec(), self.field, "x")
+                })
+                .collect(),
+            self.field,
+        );
+
+        if !reduced.coeffs[0].non_empty() {
+            let root = Polynomial::new(prefix.clone(), self.field, "x");
+            if !roots.contains(&root) {
+                roots.push(root);
+            }
+        }
+
+        if prefix.len() > max_deg {
+            return;
+        }
+
+        let at_zero = Polynomial::new(
+            reduced
+                .coeffs
+                .iter()
+                .map(|coef| coef.as_ref().first().copied().unwrap_or(self.field.zero()))
+                .collect(),
+            self.field,
+            "y",
+        );
+
+        for root in field_roots(&at_zero) {
+            prefix.push(root);
+            reduced.shift_y(root).y_roots_(prefix, max_deg, roots);
+            prefix.pop();
+        }
+    }
+
+    /// Substitutes x * y + `root` in place of y.
+    fn shift_y(&self, root: FieldElement<'a>) -> BivariatePolynomial<'a> {
+        let step = BivariatePolynomial::from_terms(
+            vec![(0, 0, root), (1, 1, self.field.one())],
+            self.field,
+        );
+
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(BivariatePolynomial::new(vec![], self.field), |acc, coef| {
+                acc * step.clone() + BivariatePolynomial::new(vec![coef.clone()], self.field)
+            })
+    }
+
+    fn check_bin_op_args(lhs: &BivariatePolynomial, rhs: &BivariatePolynomial) {
+        if !std::ptr::eq(lhs.field, rhs.field) {
+            panic!(
+                "Polynomials are built over different fields: {} and {}!",
+                lhs.field, rhs.field
+            );
+        }
+    }
+}
+
+/// Distinct roots of the univariate polynomial `poly` in its field: the product of linear
+/// factors gcd(poly, x^k - x) is split by gcd with (x + a)^((k - 1) / 2) - 1 for a = 0, 1, ...
+fn field_roots<'a>(poly: &Polynomial<'a>) -> Vec<FieldElement<'a>> {
+    let mut roots = vec![];
+    if poly.deg() < 1 {
+        return roots;
+    }
+
+    let x = Polynomial::new(
+        vec![poly.field().zero(), poly.field().one()],
+        poly.field(),
+        poly.var(),
+    );
+    let x_pow = pow_mod(&x, poly.field().k_modulus, poly);
+    split_linear_factors(&gcd(poly.clone(), x_pow - x), &mut roots);
+
+    roots
+}
+
+/// Collects roots of the monic polynomial `poly` that is a product of distinct linear factors.
+fn split_linear_factors<'a>(poly: &Polynomial<'a>, roots: &mut Vec<FieldElement<'a>>) {
+    let field = poly.field();
+    let coeffs = poly.as_ref();
+
+    match poly.deg() {
+        deg if deg < 1 => return,
+        1 => {
+            roots.push(-coeffs[0] / coeffs[1]);
+            return;
+        }
+        _ => {}
+    }
+
+    // GF(2) is the only prime field of characteristic 2 and the only product
+    // of its distinct linear factors of degree 2 is x * (x + 1)
+    if field.k_modulus == 2 {
+        roots.extend([field.zero(), field.one()]);
+        return;
+    }
+
+    // Every shift splits the factors with probability about 1/2, so only a few
+    // shifts are tried in practice, and some shift always splits them
+    let one = Polynomial::new(vec![field.one()], field, poly.var());
+    for shift in 0..field.k_modulus {
+        let base = Polynomial::new(
+            vec![field.new_element(shift as i128), field.one()],
+            field,
+            poly.var(),
+        );
+        let half_pow = pow_mod(&base, (field.k_modulus - 1) / 2, poly);
+        let factor = gcd(poly.clone(), half_pow - one.clone());
+
+        if factor.deg() > 0 && factor.deg() < poly.deg() {
+            split_linear_factors(&factor, roots);
+            split_linear_factors(&poly.div_rem(&factor).0, roots);
+            return;
+        }
+    }
+
+    unreachable!("Product of distinct linear factors is always split by some shift.");
+}
+
+/// Calculates base^exp mod `modulus` with square-and-multiply.
+fn pow_mod<'a>(base: &Polynomial<'a>, mut exp: u64, modulus: &Polynomial<'a>) -> Polynomial<'a> {
+    let one = Polynomial::new(vec![base.field().one()], base.field(), base.var());
+    let mut res = one.div_rem(modulus).1;
+    let mut cur_pow = base.div_rem(modulus).1;
+
+    while exp > 0 {
+        if exp % 2 != 0 {
+            res = (res * cur_pow.clone()).div_rem(modulus).1;
+        }
+        exp /= 2;
+        cur_pow = (cur_pow.clone() * cur_pow).div_rem(modulus).1;
+    }
+
+    res
+}
+
+/// Monic greatest common divisor of the polynomials.
+fn gcd<'a>(mut lhs: Polynomial<'a>, mut rhs: Polynomial<'a>) -> Polynomial<'a> {
+    while rhs.non_empty() {
+        let rem = lhs.div_rem(&rhs).1;
+        lhs = rhs;
+        rhs = rem;
+    }
+
+    match lhs.as_ref().last() {
+        Some(&lead) => lhs.clone() / lead,
+        None => lhs,
+    }
+}
+
+impl<'a> cmp::PartialEq<BivariatePolynomial<'a>> for BivariatePolynomial<'a> {
+    fn eq(&self, other: &BivariatePolynomial<'a>) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+impl<'a> ops::Add<BivariatePolynomial<'a>> for BivariatePolynomial<'a> {
+    type Output = BivariatePolynomial<'a>;
+
+    fn add(self, rhs: BivariatePolynomial<'a>) -> Self::Output {
+        BivariatePolynomial::check_bin_op_args(&self, &rhs);
+
+        let len = cmp::max(self.coeffs.len(), rhs.coeffs.len());
+        let zero = Polynomial::new(vec![], self.field, "x");
+        let coeffs = (0..len)
+            .map(|j| {
+                let lhs_coef = self.coeffs.get(j).unwrap_or(&zero).clone();
+                let rhs_coef = rhs.coeffs.get(j).unwrap_or(&zero).clone();
+                lhs_coef + rhs_coef
+            })
+            .collect();
+
+        BivariatePolynomial::new(coeffs, self.field)
+    }
+}
+
+impl<'a> ops::Mul<BivariatePolynomial<'a>> for BivariatePolynomial<'a> {
+    type Output = BivariatePolynomial<'a>;
+
+    fn mul(self, rhs: BivariatePolynomial<'a>) -> Self::Output {
+        BivariatePolynomial::check_bin_op_args(&self, &rhs);
+
+        let len = (self.coeffs.len() + rhs.coeffs.len()).saturating_sub(1);
+        let mut coeffs = vec![Polynomial::new(vec![], self.field, "x"); len];
+
+        for (i, lhs_coef) in self.coeffs.iter().enumerate() {
+            for (j, rhs_coef) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j].clone() + lhs_coef.clone() * rhs_coef.clone();
+            }
+        }
+
+        BivariatePolynomial::new(coeffs, self.field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BivariatePolynomial;
+    use crate::field::GaloisField;
+    use crate::polynomial::Polynomial;
+    use crate::utils;
+
+    use itertools::izip;
+
+    static FIELD: GaloisField = crate::galois_field!();
+
+    fn bivariate(terms: Vec<(usize, usize, i128)>) -> BivariatePolynomial<'static> {
+        BivariatePolynomial::from_terms(
+            terms
+                .into_iter()
+                .map(|(i, j, val)| (i, j, FIELD.new_element(val)))
+                .collect(),
+            &FIELD,
+        )
+    }
+
+    fn poly(nums: Vec<i128>) -> Polynomial<'static> {
+        Polynomial::new(utils::nums_to_elements(nums, &FIELD), &FIELD, "x")
+    }
+
+    #[test]
+    fn mul_test() {
+        // (y - x - 1) * (y - x^2) = y^2 - (x^2 + x + 1) * y + x^3 + x^2
+        let lhs = bivariate(vec![(0, 1, 1), (1, 0, -1), (0, 0, -1)]);
+        let rhs = bivariate(vec![(0, 1, 1), (2, 0, -1)]);
+        let product = bivariate(vec![
+            (0, 2, 1),
+            (2, 1, -1),
+            (1, 1, -1),
+            (0, 1, -1),
+            (3, 0, 1),
+            (2, 0, 1),
+        ]);
+
+        assert_eq!(lhs.clone() * rhs.clone(), product);
+        assert_eq!(
+            (lhs.clone() + rhs.clone()).coeffs(),
+            [poly(vec![-1, -1, -1]), poly(vec![2])]
+        );
+        assert_eq!(product.deg_x(), 3);
+        assert_eq!(product.deg_y(), 2);
+        assert_eq!(product.weighted_deg(1, 2), 4);
+        assert_eq!(product.weighted_deg(1, 1), 3);
+        assert_eq!(
+            BivariatePolynomial::new(vec![], &FIELD).weighted_deg(1, 1),
+            -1
+        );
+
+        for y in [poly(vec![]), poly(vec![3, 7]), poly(vec![-1, 0, 5])] {
+            assert_eq!(
+                product.evaluate_y(&y),
+                lhs.evaluate_y(&y) * rhs.evaluate_y(&y)
+            );
+        }
+    }
+
+    #[test]
+    fn y_roots_test() {
+        let factors_data = [
+            vec![vec![1, 1], vec![0, 0, 1]],
+            vec![vec![3], vec![5], vec![-7]],
+            vec![vec![], vec![0, 1]],
+            vec![vec![17, 0, -3, 2821], vec![1039791324, 2]],
+        ];
+        let max_deg_data = [2, 0, 1, 3];
+
+        for (factors, max_deg) in izip!(factors_data, max_deg_data) {
+            let factors = factors.into_iter().map(poly).collect::<Vec<_>>();
+            // y^2 - x has no polynomial roots, so only the linear factors contribute
+            let irreducible = bivariate(vec![(0, 2, 1), (1, 0, -1)]);
+            let product = factors.iter().fold(irreducible, |acc, factor| {
+                acc * BivariatePolynomial::new(vec![-factor.clone(), poly(vec![1])], &FIELD)
+            });
+
+            let mut roots = product.y_roots(max_deg);
+            roots.sort_by(|lhs, rhs| lhs.cmp_canonical(rhs));
+            let mut expected = factors.clone();
+            expected.sort_by(|lhs, rhs| lhs.cmp_canonical(rhs));
+
+            assert_eq!(roots, expected);
+        }
+
+        let product = bivariate(vec![(0, 1, 1), (1, 0, -1), (0, 0, -1)])
+            * bivariate(vec![(0, 1, 1), (2, 0, -1)]);
+        assert_eq!(product.y_roots(1), vec![poly(vec![1, 1])]);
+
+        static BINARY_FIELD: GaloisField = GaloisField::new(2, 1);
+        let binary = |terms: Vec<(usize, usize, i128)>| {
+            BivariatePolynomial::from_terms(
+                terms
+                    .into_iter()
+                    .map(|(i, j, val)| (i, j, BINARY_FIELD.new_element(val)))
+                    .collect(),
+                &BINARY_FIELD,
+            )
+        };
+        // (y + 1) * y * (y + x) * (y^2 + y + 1) over GF(2)
+        let product = binary(vec![(0, 1, 1), (0, 0, 1)])
+            * binary(vec![(0, 1, 1)])
+            * binary(vec![(0, 1, 1), (1, 0, 1)])
+            * binary(vec![(0, 2, 1), (0, 1, 1), (0, 0, 1)]);
+        let mut roots = product.y_roots(1);
+        roots.sort_by(|lhs, rhs| lhs.cmp_canonical(rhs));
+        assert_eq!(
+            roots,
+            [vec![], vec![1], vec![0, 1]]
+                .into_iter()
+                .map(|raw| Polynomial::new(
+                    utils::nums_to_elements(raw, &BINARY_FIELD),
+                    &BINARY_FIELD,
+                    "x"
+                ))
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use std::{error, fmt};
+
+/// Errors of the field, polynomial and coding operations
+/// that are reported to the caller instead of panicking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RsError {
+    /// Polynomial is divided by the zero polynomial.
+    DivisionByZero,
+    /// Definition area of the interpolation contains the same point twice.
+    DuplicateInterpolationPoint,
+    /// Polynomial is deflated by an element that is not its root.
+    NotARoot,
+    /// Degree of the constructing polynomial exceeds the allowed maximum.
+    DegreeTooLarge { deg: usize, max_deg: usize },
+    /// Polynomial is interpolated over the empty set of points.
+    EmptyInterpolationSet,
+    /// Numbers of interpolation points and values differ.
+    InterpolationLengthMismatch { points: usize, values: usize },
+    /// Elements of the operation lay in different fields.
+    FieldMismatch,
+}
+
+impl fmt::Display for RsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RsError::DivisionByZero => write!(f, "division by the zero polynomial"),
+            RsError::DuplicateInterpolationPoint => {
+                write!(f, "interpolation points are not distinct")
+            }
+            RsError::NotARoot => write!(f, "element is not a root of the polynomial"),
+            RsError::DegreeTooLarge { deg, max_deg } => write!(
+                f,
+                "polynomial degree {} exceeds the maximum degree {}",
+                deg, max_deg
+            ),
+            RsError::EmptyInterpolationSet => {
+                write!(f, "interpolation set of points is empty")
+            }
+            RsError::InterpolationLengthMismatch { points, values } => write!(
+                f,
+                "{} interpolation points don't match {} values",
+                points, values
+            ),
+            RsError::FieldMismatch => write!(f, "elements lay in different fields"),
+        }
+    }
+}
+
+impl error::Error for RsError {}
+
+/// Errors of parsing field elements and polynomials from their textual
+/// or binary representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// String is neither a decimal nor a `0x`-prefixed hexadecimal number.
+    InvalidNumber(String),
+    /// Length of the byte sequence is not a multiple of the symbol width.
+    MisalignedLength { len: usize, symbol_width: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidNumber(s) => write!(f, "invalid number: {:?}", s),
+            ParseError::MisalignedLength { len, symbol_width } => write!(
+                f,
+                "length {} is not a multiple of the symbol width {}",
+                len, symbol_width
+            ),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
@@ -1,4 +1,14 @@
+use crate::error::ParseError;
 use crate::field_element::FieldElement;
+use crate::polynomial::Endianness;
+
+use std::collections::HashMap;
+use std::{cmp, fmt, iter};
+
+#[cfg(feature = "rand")]
+use rand::RngCore;
+#[cfg(feature = "simd")]
+use wide::u64x4;
 
 /// Finite field with order of 2^power. Cause we always works with a chunks of data
 /// multiple of octet, the number of field elements always will be a power of 2.
@@ -9,21 +19,125 @@ pub struct GaloisField {
 
     pub zero: u64,
     pub one: u64,
+
+    /// Barrett constant floor(2^64 / k_modulus) used for the fast modular reduction.
+    pub barrett_const: u64,
+}
+
+impl fmt::Display for GaloisField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GF({}) with generator {}",
+            self.k_modulus, self.generator_val
+        )
+    }
 }
 
 #[macro_export]
 macro_rules! galois_field {
     () => {
-        $crate::field::GaloisField {
-            k_modulus: 3 * u64::pow(2, 30) + 1,
-            generator_val: 5,
-            zero: 0,
-            one: 1,
-        }
+        $crate::field::GaloisField::new_checked(3 * u64::pow(2, 30) + 1, 5)
     };
 }
 
+/// Default field built by `galois_field!`, provides `FieldElement::default()`.
+/// Fields are compared by address, so share it as `&DEFAULT_FIELD` rather than
+/// building another field with the same parameters.
+pub static DEFAULT_FIELD: GaloisField = galois_field!();
+
 impl GaloisField {
+    /// Creates field with the prime modulus `k_modulus` (that should be less than 2^32)
+    /// and the generator of its multiplicative group `generator_val`.
+    pub const fn new(k_modulus: u64, generator_val: u64) -> GaloisField {
+        GaloisField {
+            k_modulus,
+            generator_val,
+            zero: 0,
+            one: 1,
+            barrett_const: ((1u128 << 64) / k_modulus as u128) as u64,
+        }
+    }
+
+    /// Same as `new`, but panics if the field is invalid (see `assert_valid`).
+    /// Evaluated at compile time when used to initialize constants and statics,
+    /// so an invalid field is rejected by the compiler.
+    pub const fn new_checked(k_modulus: u64, generator_val: u64) -> GaloisField {
+        assert!(
+            k_modulus >= 2 && k_modulus <= u32::MAX as u64,
+            "Field modulus should be in range [2, 2^32)."
+        );
+        assert!(is_prime(k_modulus), "Field modulus should be prime.");
+        assert!(
+            is_primitive_root(generator_val, k_modulus),
+            "Field generator should generate the multiplicative group."
+        );
+
+        GaloisField::new(k_modulus, generator_val)
+    }
+
+    /// Finds the least prime k_modulus = c * 2^t + 1 with `bit_width` not less than `min_bits`
+    /// (k_modulus > 2^(min_bits - 1)) and 2-adicity t not less than `min_two_adicity`,
+    /// and builds field with its least primitive root as the generator.
+    /// Returns `None` if there is no such prime less than 2^32.
+    pub fn find_ntt_prime(min_bits: u32, min_two_adicity: u32) -> Option<GaloisField> {
+        if min_bits > u32::BITS || min_two_adicity >= u32::BITS {
+            return None;
+        }
+
+        let step = 1u64 << min_two_adicity;
+        let min_modulus = (1u64 << min_bits.saturating_sub(1)) + 1;
+        let first = cmp::max((min_modulus - 1 + step - 1) / step, 1);
+
+        (first..)
+            .map(|c| c * step + 1)
+            .take_while(|&k_modulus| k_modulus <= u32::MAX as u64)
+            .find(|&k_modulus| is_prime(k_modulus))
+            .map(|k_modulus| {
+                let generator_val = (2..k_modulus)
+                    .find(|&g| is_primitive_root(g, k_modulus))
+                    .unwrap_or(1);
+                GaloisField::new(k_modulus, generator_val)
+            })
+    }
+
+    /// Panics if `k_modulus` is not a prime less than 2^32, `generator_val` doesn't generate
+    /// the multiplicative group of the field or the precomputed constants are inconsistent.
+    pub fn assert_valid(&self) {
+        if self.k_modulus < 2 || self.k_modulus > u32::MAX as u64 {
+            panic!("Invalid field {}: modulus is out of range [2, 2^32).", self);
+        }
+        if !is_prime(self.k_modulus) {
+            panic!("Invalid field {}: modulus is not prime.", self);
+        }
+        if !is_primitive_root(self.generator_val, self.k_modulus) {
+            panic!(
+                "Invalid field {}: generator doesn't generate the multiplicative group.",
+                self
+            );
+        }
+        if self.zero != 0
+            || self.one != 1
+            || self.barrett_const != ((1u128 << 64) / self.k_modulus as u128) as u64
+        {
+            panic!("Invalid field {}: inconsistent field constants.", self);
+        }
+    }
+
+    /// Reduces `x` modulo `k_modulus` using Barrett reduction instead of division.
+    /// `x` should be less than k_modulus^2, e.g. the product of two canonical values.
+    pub fn barrett_reduce(&self, x: u128) -> u64 {
+        debug_assert!(x < self.k_modulus as u128 * self.k_modulus as u128);
+
+        let quotient = (x * self.barrett_const as u128) >> 64;
+        let mut rem = (x - quotient * self.k_modulus as u128) as u64;
+        while rem >= self.k_modulus {
+            rem -= self.k_modulus;
+        }
+
+        rem
+    }
+
     pub fn new_element(&self, element_val: i128) -> FieldElement {
         FieldElement {
             val: element_val.rem_euclid(self.k_modulus as i128) as u64,
@@ -51,11 +165,409 @@ impl GaloisField {
             field: self,
         }
     }
+
+    /// Infinite sequence of the generator powers generator^0, generator^1, ...,
+    /// that cycles back to one after every `k_modulus - 1` elements.
+    pub fn powers_of_generator(&self) -> impl Iterator<Item = FieldElement> + '_ {
+        self.powers(self.generator())
+    }
+
+    /// First `count` powers of `base`: base^0, base^1, ..., base^(count - 1).
+    pub fn powers_of<'a>(
+        &'a self,
+        base: FieldElement<'a>,
+        count: usize,
+    ) -> impl Iterator<Item = FieldElement<'a>> + 'a {
+        if !std::ptr::eq(self, base.field) {
+            panic!(
+                "Element lays in different field: {} instead of {}.",
+                base.field, self
+            );
+        }
+
+        self.powers(base).take(count)
+    }
+
+    fn powers<'a>(&'a self, base: FieldElement<'a>) -> impl Iterator<Item = FieldElement<'a>> {
+        iter::successors(Some(self.one()), move |&cur| Some(cur * base))
+    }
+
+    /// Checks whether `el` lays in this field and its value is canonical, i.e. less than `k_modulus`.
+    pub fn contains(&self, el: &FieldElement) -> bool {
+        std::ptr::eq(self, el.field) && el.val < self.k_modulus
+    }
+
+    /// Characteristic of the field, equal to `k_modulus` for the prime field.
+    pub fn characteristic(&self) -> u64 {
+        self.k_modulus
+    }
+
+    /// Degree of the field extension over its prime subfield, 1 for the prime field.
+    pub fn extension_degree(&self) -> u32 {
+        1
+    }
+
+    /// Number of elements of the field, equal to characteristic^extension_degree.
+    pub fn order(&self) -> u128 {
+        (self.characteristic() as u128).pow(self.extension_degree())
+    }
+
+    /// Greatest t such that 2^t divides k_modulus - 1, i.e. the greatest power of 2
+    /// that can be the length of the number theoretic transform over the field.
+    pub fn two_adicity(&self) -> u32 {
+        (self.k_modulus - 1).trailing_zeros()
+    }
+
+    /// Number of bits that fits any element of the field, ceil(log2(k_modulus)).
+    pub fn bit_width(&self) -> u32 {
+        u64::BITS - (self.k_modulus - 1).leading_zeros()
+    }
+
+    /// Number of bytes that fits any element of the field, at least one.
+    /// Width of the symbols in the binary representation of elements.
+    pub fn byte_width(&self) -> usize {
+        cmp::max((self.bit_width() as usize + 7) / 8, 1)
+    }
+
+    /// Parses field element from decimal (optionally negative) or `0x`-prefixed
+    /// hexadecimal string, the parsed value is reduced modulo `k_modulus`.
+    pub fn parse_element(&self, s: &str) -> Result<FieldElement, ParseError> {
+        let invalid = || ParseError::InvalidNumber(s.to_string());
+        let trimmed = s.trim();
+        let (negative, digits) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+
+        let (radix, digits) = match digits
+            .strip_prefix("0x")
+            .or_else(|| digits.strip_prefix("0X"))
+        {
+            Some(rest) => (16, rest),
+            None => (10, digits),
+        };
+
+        if digits.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut val = 0u128;
+        for c in digits.chars() {
+            let digit = c.to_digit(radix).ok_or_else(invalid)?;
+            val = (val * radix as u128 + digit as u128) % self.k_modulus as u128;
+        }
+
+        Ok(if negative {
+            self.new_element(-(val as i128))
+        } else {
+            self.new_element(val as i128)
+        })
+    }
+
+    /// Reads elements from `bytes` split into symbols of `symbol_width` bytes each,
+    /// every symbol value is reduced modulo `k_modulus`.
+    /// Fails if the length of `bytes` is not a multiple of the symbol width.
+    pub fn elements_from_bytes(
+        &self,
+        bytes: &[u8],
+        endianness: Endianness,
+    ) -> Result<Vec<FieldElement>, ParseError> {
+        let symbol_width = self.byte_width();
+
+        if bytes.len() % symbol_width != 0 {
+            return Err(ParseError::MisalignedLength {
+                len: bytes.len(),
+                symbol_width,
+            });
+        }
+
+        Ok(bytes
+            .chunks(symbol_width)
+            .map(|symbol| {
+                let fold = |acc, &byte| (acc << 8) | byte as u64;
+                let val = match endianness {
+                    Endianness::Little => symbol.iter().rev().fold(0u64, fold),
+                    Endianness::Big => symbol.iter().fold(0u64, fold),
+                };
+                self.new_element(val as i128)
+            })
+            .collect())
+    }
+
+    /// Serializes `elems` in the format read by `elements_from_bytes`.
+    pub fn elements_to_bytes(&self, elems: &[FieldElement], endianness: Endianness) -> Vec<u8> {
+        let symbol_width = self.byte_width();
+        let mut bytes = Vec::with_capacity(elems.len() * symbol_width);
+
+        for el in elems.iter() {
+            if !std::ptr::eq(self, el.field) {
+                panic!(
+                    "Element lays in different field: {} instead of {}.",
+                    el.field, self
+                );
+            }
+
+            let symbol = &el.canonical_val().to_le_bytes()[..symbol_width];
+            match endianness {
+                Endianness::Little => bytes.extend(symbol.iter()),
+                Endianness::Big => bytes.extend(symbol.iter().rev()),
+            }
+        }
+
+        bytes
+    }
+
+    /// Sums sequence of elements, the sum of empty sequence is zero.
+    pub fn sum<'a, I>(&'a self, iter: I) -> FieldElement<'a>
+    where
+        I: IntoIterator<Item = FieldElement<'a>>,
+    {
+        iter.into_iter().fold(self.zero(), |acc, el| acc + el)
+    }
+
+    /// Multiplies sequence of elements, the product of empty sequence is one.
+    pub fn product<'a, I>(&'a self, iter: I) -> FieldElement<'a>
+    where
+        I: IntoIterator<Item = FieldElement<'a>>,
+    {
+        iter.into_iter().fold(self.one(), |acc, el| acc * el)
+    }
+
+    /// Detection-only checksum of `data`: value of the polynomial with coefficients `data`,
+    /// listed from the highest power, at the generator point, calculated with Horner's scheme.
+    /// Any change of a single symbol changes the checksum.
+    pub fn checksum<'a>(&'a self, data: &[FieldElement<'a>]) -> FieldElement<'a> {
+        data.iter()
+            .fold(self.zero(), |acc, &el| acc * self.generator() + el)
+    }
+
+    /// Checks whether `checksum` is the checksum of `data`.
+    pub fn verify_checksum<'a>(
+        &'a self,
+        data: &[FieldElement<'a>],
+        checksum: FieldElement<'a>,
+    ) -> bool {
+        self.checksum(data) == checksum
+    }
+
+    /// Raises every element of `elems` to the same power `n`. Binary expansion of `n`
+    /// is calculated once and the same square-and-multiply chain is applied to every element.
+    pub fn pow_batch<'a>(&'a self, elems: &[FieldElement<'a>], n: u64) -> Vec<FieldElement<'a>> {
+        let bits = (0..u64::BITS - n.leading_zeros())
+            .rev()
+            .map(|i| (n >> i) & 1 == 1)
+            .collect::<Vec<bool>>();
+
+        elems
+            .iter()
+            .map(|&el| {
+                if !std::ptr::eq(self, el.field) {
+                    panic!(
+                        "Element lays in different field: {} instead of {}.",
+                        el.field, self
+                    );
+                }
+
+                bits.iter().fold(self.one(), |acc, &bit| {
+                    if bit {
+                        acc.square() * el
+                    } else {
+                        acc.square()
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Finds exponent `e` such that `generator^e == x` using baby-step giant-step algorithm.
+    /// Returns `None` for zero and for elements outside of the cyclic group spanned by the generator.
+    pub fn discrete_log(&self, x: FieldElement) -> Option<u64> {
+        if !std::ptr::eq(self, x.field) {
+            panic!(
+                "Element lays in different field: {} instead of {}.",
+                x.field, self
+            );
+        }
+
+        if x.canonical_val() == self.zero {
+            return None;
+        }
+
+        let order = self.k_modulus - 1;
+        let mut m = (order as f64).sqrt() as u64;
+        while m * m < order {
+            m += 1;
+        }
+
+        let mut baby_steps = HashMap::with_capacity(m as usize);
+        let mut cur = self.one();
+        for j in 0..m {
+            baby_steps.entry(cur.canonical_val()).or_insert(j);
+            cur *= self.generator();
+        }
+
+        let giant_step = self.generator().pow(m as u32).inverse();
+        let mut gamma = x;
+        for i in 0..m {
+            if let Some(j) = baby_steps.get(&gamma.canonical_val()) {
+                return Some(i * m + j);
+            }
+            gamma *= giant_step;
+        }
+
+        None
+    }
+
+    /// Samples field element uniformly from `[0, k_modulus)`.
+    /// Random values are masked to the bit length of the modulus and rejected
+    /// if they exceed it, so the result has no modulo bias.
+    #[cfg(feature = "rand")]
+    pub fn random_element<R: RngCore>(&self, rng: &mut R) -> FieldElement {
+        let mask = self.k_modulus.next_power_of_two() - 1;
+
+        loop {
+            let val = rng.next_u64() & mask;
+            if val < self.k_modulus {
+                return FieldElement { val, field: self };
+            }
+        }
+    }
+
+    /// Multiplies elements of `a` and `b` pairwise. Values are multiplied four lanes
+    /// at a time with SIMD instructions if the target supports them (products of values
+    /// less than 2^32 fit into 64-bit lanes) and then reduced modulo `k_modulus`.
+    #[cfg(feature = "simd")]
+    pub fn mul_batch<'a>(
+        &'a self,
+        a: &[FieldElement<'a>],
+        b: &[FieldElement<'a>],
+    ) -> Vec<FieldElement<'a>> {
+        if a.len() != b.len() {
+            panic!("Impossible to multiply batches of different length.");
+        }
+        if a.iter()
+            .chain(b.iter())
+            .any(|el| !std::ptr::eq(self, el.field))
+        {
+            panic!("Not all elements are lay in the same field.");
+        }
+
+        let mut res = Vec::with_capacity(a.len());
+        let lanes = |chunk: &[FieldElement]| {
+            u64x4::new([
+                chunk[0].canonical_val(),
+                chunk[1].canonical_val(),
+                chunk[2].canonical_val(),
+                chunk[3].canonical_val(),
+            ])
+        };
+
+        let (a_chunks, b_chunks) = (a.chunks_exact(4), b.chunks_exact(4));
+        let (a_rem, b_rem) = (a_chunks.remainder(), b_chunks.remainder());
+        for (a_chunk, b_chunk) in a_chunks.zip(b_chunks) {
+            let products = lanes(a_chunk) * lanes(b_chunk);
+            res.extend(products.to_array().iter().map(|&product| FieldElement {
+                val: self.barrett_reduce(product as u128),
+                field: self,
+            }));
+        }
+        res.extend(a_rem.iter().zip(b_rem.iter()).map(|(&lhs, &rhs)| lhs * rhs));
+
+        res
+    }
+}
+
+const fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut res = 1 % modulus;
+    base %= modulus;
+
+    while exp > 0 {
+        if exp % 2 != 0 {
+            res = ((res as u128 * base as u128) % modulus as u128) as u64;
+        }
+        base = ((base as u128 * base as u128) % modulus as u128) as u64;
+        exp /= 2;
+    }
+
+    res
+}
+
+/// Deterministic Miller–Rabin primality test, the bases are sufficient for any 64-bit `n`.
+const fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < BASES.len() {
+        if n % BASES[i] == 0 {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+
+    let (mut d, mut s) = (n - 1, 0);
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+
+    let mut i = 0;
+    while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, n);
+        let mut r = 1;
+        let mut composite = x != 1 && x != n - 1;
+
+        while composite && r < s {
+            x = ((x as u128 * x as u128) % n as u128) as u64;
+            composite = x != n - 1;
+            r += 1;
+        }
+        if composite {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Checks whether `g` generates the multiplicative group of the prime field of order `k`,
+/// i.e. g^((k - 1) / q) != 1 for every prime divisor q of k - 1.
+const fn is_primitive_root(g: u64, k: u64) -> bool {
+    if g == 0 || g >= k {
+        return false;
+    }
+
+    let order = k - 1;
+    let mut rest = order;
+    let mut q = 2;
+
+    while q * q <= rest {
+        if rest % q == 0 {
+            if pow_mod(g, order / q, k) == 1 {
+                return false;
+            }
+            while rest % q == 0 {
+                rest /= q;
+            }
+        }
+        q += 1;
+    }
+
+    rest == 1 || pow_mod(g, order / rest, k) != 1
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{GaloisField, DEFAULT_FIELD};
+    use crate::error::ParseError;
     use crate::field_element::FieldElement;
+    use crate::polynomial::Endianness;
+
+    use std::collections::HashSet;
 
     #[test]
     fn init_test() {
@@ -114,4 +626,421 @@ mod tests {
             assert_eq!(field.new_element(init_val).val, el_val);
         }
     }
+
+    #[test]
+    fn default_test() {
+        let macro_field = galois_field!();
+
+        assert_eq!(DEFAULT_FIELD.k_modulus, macro_field.k_modulus);
+        assert_eq!(DEFAULT_FIELD.generator_val, macro_field.generator_val);
+        assert_eq!(DEFAULT_FIELD.barrett_const, macro_field.barrett_const);
+        assert_eq!(FieldElement::default(), DEFAULT_FIELD.zero());
+        assert_eq!(
+            FieldElement::default() + DEFAULT_FIELD.one(),
+            DEFAULT_FIELD.one()
+        );
+    }
+
+    #[test]
+    fn sum_product_test() {
+        let field = galois_field!();
+        let elements = crate::utils::nums_to_elements(
+            vec![-1086477693, 1462968467, -981229042, 238649413, 2113760060],
+            &field,
+        );
+
+        assert_eq!(field.sum(vec![]), field.zero());
+        assert_eq!(field.product(vec![]), field.one());
+        assert_eq!(
+            field.sum(elements.iter().copied()),
+            elements.iter().copied().sum()
+        );
+        assert_eq!(
+            field.product(elements.iter().copied()),
+            elements.iter().copied().product()
+        );
+    }
+
+    #[test]
+    fn barrett_reduce_test() {
+        let field = galois_field!();
+        let k = field.k_modulus as u128;
+        let mut test_data = vec![0, 1, k - 1, k, k + 1, 2 * k - 1, k * k - k, k * k - 1];
+
+        let mut x = 0x9E3779B97F4A7C15u128;
+        for _ in 0..100_000 {
+            x = (x * 6364136223846793005 + 1442695040888963407) % (1 << 64);
+            test_data.push(x % (k * k));
+        }
+
+        for x in test_data {
+            assert_eq!(field.barrett_reduce(x) as u128, x % k);
+        }
+
+        for (k_modulus, generator_val) in [(2, 1), (17, 3), (65537, 3), (4294967291, 2)] {
+            let field = GaloisField::new(k_modulus, generator_val);
+            let k = k_modulus as u128;
+            for x in [0, 1, k - 1, k, k * k / 2, k * k - 1] {
+                assert_eq!(field.barrett_reduce(x) as u128, x % k);
+            }
+        }
+    }
+
+    #[test]
+    fn contains_test() {
+        let field = galois_field!();
+        let other_field = galois_field!();
+
+        assert!(field.contains(&field.zero()));
+        assert!(field.contains(&field.new_element(-1)));
+        assert!(!field.contains(&other_field.one()));
+        assert!(!field.contains(&FieldElement {
+            val: field.k_modulus,
+            field: &field
+        }));
+        assert!(!field.contains(&FieldElement {
+            val: u64::MAX,
+            field: &field
+        }));
+    }
+
+    #[test]
+    fn assert_valid_test() {
+        galois_field!().assert_valid();
+
+        for (k_modulus, generator_val) in [(2, 1), (17, 3), (251, 6), (65537, 3), (4294967291, 2)] {
+            GaloisField::new(k_modulus, generator_val).assert_valid();
+            GaloisField::new_checked(k_modulus, generator_val);
+        }
+
+        let invalid_data = [
+            ((15, 2), "modulus is not prime"),
+            ((3221225475, 5), "modulus is not prime"),
+            ((1, 1), "modulus is out of range"),
+            ((4294967311, 3), "modulus is out of range"),
+            ((17, 4), "generator doesn't generate"),
+            ((17, 0), "generator doesn't generate"),
+            ((3221225473, 3221225472), "generator doesn't generate"),
+        ];
+
+        for ((k_modulus, generator_val), message) in invalid_data {
+            let err = std::panic::catch_unwind(|| {
+                GaloisField::new(k_modulus, generator_val).assert_valid()
+            })
+            .unwrap_err();
+            assert!(err.downcast_ref::<String>().unwrap().contains(message));
+
+            assert!(std::panic::catch_unwind(|| {
+                GaloisField::new_checked(k_modulus, generator_val)
+            })
+            .is_err());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "inconsistent field constants")]
+    fn assert_valid_constants_test() {
+        let mut field = galois_field!();
+        field.barrett_const += 1;
+        field.assert_valid();
+    }
+
+    #[test]
+    fn structure_test() {
+        let field = galois_field!();
+
+        assert_eq!(field.characteristic(), 3221225473);
+        assert_eq!(field.extension_degree(), 1);
+        assert_eq!(field.order(), 3221225473);
+    }
+
+    #[test]
+    fn width_test() {
+        let field = galois_field!();
+        assert_eq!(field.bit_width(), 32);
+        assert_eq!(field.byte_width(), 4);
+
+        for (k_modulus, generator_val, bit_width, byte_width) in [
+            (2, 1, 1, 1),
+            (17, 3, 5, 1),
+            (251, 6, 8, 1),
+            (257, 3, 9, 2),
+            (65537, 3, 17, 3),
+        ] {
+            let field = GaloisField::new(k_modulus, generator_val);
+            assert_eq!(field.bit_width(), bit_width);
+            assert_eq!(field.byte_width(), byte_width);
+        }
+    }
+
+    #[test]
+    fn find_ntt_prime_test() {
+        let field = galois_field!();
+        assert_eq!(field.two_adicity(), 30);
+        assert_eq!(GaloisField::new(17, 3).two_adicity(), 4);
+        assert_eq!(GaloisField::new(2, 1).two_adicity(), 0);
+
+        let found = GaloisField::find_ntt_prime(32, 30).unwrap();
+        assert_eq!(found.k_modulus, field.k_modulus);
+        assert_eq!(found.generator_val, field.generator_val);
+        assert_eq!(GaloisField::find_ntt_prime(10, 8).unwrap().k_modulus, 769);
+
+        for (min_bits, min_two_adicity) in [
+            (1, 0),
+            (2, 0),
+            (8, 4),
+            (16, 16),
+            (20, 12),
+            (31, 24),
+            (32, 27),
+        ] {
+            let field = GaloisField::find_ntt_prime(min_bits, min_two_adicity).unwrap();
+            field.assert_valid();
+            assert!(field.bit_width() >= min_bits);
+            assert!(field.two_adicity() >= min_two_adicity);
+        }
+
+        assert!(GaloisField::find_ntt_prime(32, 31).is_none());
+        assert!(GaloisField::find_ntt_prime(33, 1).is_none());
+    }
+
+    #[test]
+    fn parse_element_test() {
+        let field = galois_field!();
+        let test_data = [
+            ("0", 0),
+            ("42", 42),
+            (" 3221225473 ", 0),
+            ("-1", 3221225472),
+            ("0x1F", 31),
+            ("0XfF", 255),
+            ("-0x10", 3221225457),
+            ("0xC0000002", 1),
+            ("340282366920938463463374607431768211455", 2823543318),
+            ("0x1000000000000000000000000000000000", 79536482),
+        ];
+
+        for (s, val) in test_data {
+            assert_eq!(field.parse_element(s), Ok(field.new_element(val)));
+        }
+
+        for s in [
+            "", "-", "0x", "12a", "0xg1", "1.5", "--1", "+1", "1 2", "0x-1",
+        ] {
+            assert_eq!(
+                field.parse_element(s),
+                Err(ParseError::InvalidNumber(s.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn elements_bytes_test() {
+        static SMALL_FIELD: GaloisField = GaloisField::new(251, 6);
+        let field = galois_field!();
+
+        for (field, vals) in [
+            (&field, vec![0, 1, 0xbfffffff, 0x04030201, 3221225472]),
+            (&SMALL_FIELD, vec![7, 0, 250]),
+            (&field, vec![]),
+        ] {
+            let elems = vals
+                .iter()
+                .map(|&val| field.new_element(val))
+                .collect::<Vec<_>>();
+
+            for endianness in [Endianness::Little, Endianness::Big] {
+                let bytes = field.elements_to_bytes(&elems, endianness);
+                assert_eq!(bytes.len(), elems.len() * field.byte_width());
+                assert_eq!(
+                    field.elements_from_bytes(&bytes, endianness),
+                    Ok(elems.clone())
+                );
+            }
+        }
+
+        assert_eq!(
+            field.elements_from_bytes(&[1, 0, 0, 0, 2], Endianness::Little),
+            Err(ParseError::MisalignedLength {
+                len: 5,
+                symbol_width: 4
+            })
+        );
+    }
+
+    #[test]
+    fn powers_test() {
+        static SMALL_FIELD: GaloisField = GaloisField::new(17, 3);
+        let order = SMALL_FIELD.k_modulus as usize - 1;
+
+        let powers = SMALL_FIELD
+            .powers_of_generator()
+            .take(2 * order + 1)
+            .collect::<Vec<_>>();
+        let distinct = powers[..order]
+            .iter()
+            .map(|el| el.val)
+            .collect::<HashSet<u64>>();
+
+        assert_eq!(distinct.len(), order);
+        assert_eq!(powers[order], SMALL_FIELD.one());
+        assert_eq!(powers[..order], powers[order..2 * order]);
+
+        let field = galois_field!();
+        let base = field.new_element(-2);
+        let res_data = [1, -2, 4, -8, 16, -32];
+
+        assert_eq!(field.powers_of(base, 0).count(), 0);
+        assert!(field
+            .powers_of(base, res_data.len())
+            .eq(res_data.iter().map(|&val| field.new_element(val))));
+        assert!(field
+            .powers_of_generator()
+            .take(100)
+            .eq(field.powers_of(field.generator(), 100)));
+    }
+
+    #[test]
+    fn checksum_test() {
+        let field = galois_field!();
+        let data = crate::utils::nums_to_elements(
+            vec![12, 0, 3221225472i64, 99, 1238909831, 7, 0, 0],
+            &field,
+        );
+        let checksum = field.checksum(&data);
+
+        assert!(field.verify_checksum(&data, checksum));
+        assert_eq!(field.checksum(&[]), field.zero());
+        assert_eq!(field.checksum(&data[..1]), data[0]);
+
+        for i in 0..data.len() {
+            for delta in [1, -1, 1610612736] {
+                let mut corrupted = data.clone();
+                corrupted[i] += field.new_element(delta);
+
+                assert_ne!(field.checksum(&corrupted), checksum);
+                assert!(!field.verify_checksum(&corrupted, checksum));
+            }
+        }
+    }
+
+    #[test]
+    fn pow_batch_test() {
+        let field = galois_field!();
+        let elems =
+            crate::utils::nums_to_elements(vec![0, 1, -1, 2, 5, 1238909831, 2110495054i64], &field);
+
+        for n in [0, 1, 2, 3, 17, 65536, 3221225471, u32::MAX] {
+            let res = elems.iter().map(|el| el.pow(n)).collect::<Vec<_>>();
+            assert_eq!(field.pow_batch(&elems, n as u64), res);
+        }
+
+        let order = field.k_modulus - 1;
+        for n in [order, order + 5, u64::MAX] {
+            let res = elems[1..]
+                .iter()
+                .map(|el| el.pow((n % order) as u32))
+                .collect::<Vec<_>>();
+            assert_eq!(field.pow_batch(&elems[1..], n), res);
+        }
+        assert_eq!(field.pow_batch(&[], 12), vec![]);
+    }
+
+    #[test]
+    fn discrete_log_test() {
+        let field = galois_field!();
+        let exponents = [
+            0, 1, 2, 29, 56755, 56756, 1039791324, 2110495054, 3221225471,
+        ];
+
+        for exp in exponents {
+            assert_eq!(
+                field.discrete_log(field.generator().pow(exp)),
+                Some(exp as u64)
+            );
+        }
+        assert_eq!(field.discrete_log(field.zero()), None);
+        assert_eq!(field.discrete_log(field.one()), Some(0));
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn mul_batch_test() {
+        let field = galois_field!();
+        let mut x = 0x2545F4914F6CDD1Du64;
+        let mut next = || {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            field.new_element(x as i128)
+        };
+
+        for len in [0, 1, 3, 4, 7, 64, 1001] {
+            let a = (0..len).map(|_| next()).collect::<Vec<_>>();
+            let mut b = (0..len).map(|_| next()).collect::<Vec<_>>();
+            if let Some(el) = b.first_mut() {
+                *el = field.new_element(-1);
+            }
+
+            let res = a.iter().zip(b.iter()).map(|(&lhs, &rhs)| lhs * rhs);
+            assert!(field.mul_batch(&a, &b).into_iter().eq(res));
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn mul_batch_denormalized_test() {
+        let field = galois_field!();
+        let vals = [
+            field.k_modulus + 3,
+            2 * field.k_modulus - 1,
+            u64::MAX,
+            7,
+            1238909831,
+        ];
+        let denormalized = vals
+            .iter()
+            .map(|&val| FieldElement { val, field: &field })
+            .collect::<Vec<_>>();
+        let canonical = vals
+            .iter()
+            .map(|&val| field.new_element(val as i128))
+            .collect::<Vec<_>>();
+
+        let res = field.mul_batch(&denormalized, &denormalized);
+        let expected = field.mul_batch(&canonical, &canonical);
+        assert_eq!(
+            res.iter().map(|el| el.val).collect::<Vec<_>>(),
+            expected.iter().map(|el| el.val).collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_element_test() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        const BUCKETS: usize = 16;
+        const SAMPLES: usize = 160_000;
+        // Chi-square critical value for 15 degrees of freedom at p = 0.001
+        const CRITICAL_VALUE: f64 = 37.697;
+
+        let field = galois_field!();
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counts = [0usize; BUCKETS];
+
+        for _ in 0..SAMPLES {
+            let el = field.random_element(&mut rng);
+            assert!(el.val < field.k_modulus);
+            counts[(el.val as u128 * BUCKETS as u128 / field.k_modulus as u128) as usize] += 1;
+        }
+
+        let expected = SAMPLES as f64 / BUCKETS as f64;
+        let chi_square = counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum::<f64>();
+
+        assert!(chi_square < CRITICAL_VALUE);
+    }
 }
@@ -1,5 +1,8 @@
-use crate::field::GaloisField;
-use std::{cmp, ops};
+use crate::field::{GaloisField, DEFAULT_FIELD};
+use std::{cmp, iter, ops};
+
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess};
 
 #[derive(Debug, Copy, Clone)]
 pub struct FieldElement<'a> {
@@ -8,11 +11,35 @@ pub struct FieldElement<'a> {
 }
 
 impl<'a> FieldElement<'a> {
+    /// Creates element of the `field` with value `val` reduced modulo `k_modulus`.
+    pub fn from_val(val: u64, field: &'a GaloisField) -> FieldElement<'a> {
+        FieldElement {
+            val: val % field.k_modulus,
+            field,
+        }
+    }
+
+    /// Creates element of the `field` with value `val` as is, without the reduction.
+    /// # Safety
+    /// `val` should be less than `field.k_modulus`. All arithmetic relies on the canonical
+    /// values and silently gives wrong results otherwise. The invariant is checked only
+    /// by the debug assertion.
+    pub unsafe fn from_val_unchecked(val: u64, field: &'a GaloisField) -> FieldElement<'a> {
+        debug_assert!(
+            val < field.k_modulus,
+            "Value {} is out of range of {}.",
+            val,
+            field
+        );
+
+        FieldElement { val, field }
+    }
+
     pub fn inverse(&self) -> FieldElement<'a> {
         let zero = self.field.zero as i128;
         let one = self.field.one as i128;
         let (mut t, mut new_t) = (zero, self.field.one as i128);
-        let (mut r, mut new_r) = (self.field.k_modulus as i128, self.val as i128);
+        let (mut r, mut new_r) = (self.field.k_modulus as i128, self.canonical_val() as i128);
 
         while new_r != zero {
             let quotient = r / new_r;
@@ -24,7 +51,19 @@ impl<'a> FieldElement<'a> {
         self.field.new_element(t)
     }
 
-    pub fn pow(self, mut n: u32) -> FieldElement<'a> {
+    /// Inverse of the element with the convention 0^(-1) = 0, which is not mathematically
+    /// valid but lets pipelines invert whole batches without checking for zero.
+    /// Calculated as a^(k - 2) by Fermat's little theorem, so zero needs no separate branch.
+    pub fn invert_or_zero(self) -> FieldElement<'a> {
+        self.pow_u64(self.field.k_modulus - 2)
+    }
+
+    pub fn pow(self, n: u32) -> FieldElement<'a> {
+        self.pow_u64(n as u64)
+    }
+
+    /// Same as `pow`, but for exponents that don't fit `u32`, e.g. k_modulus - 2.
+    pub fn pow_u64(self, mut n: u64) -> FieldElement<'a> {
         let mut cur_pow = self;
         let mut res = cur_pow.field.one();
 
@@ -33,11 +72,90 @@ impl<'a> FieldElement<'a> {
                 res *= cur_pow;
             }
             n /= 2;
-            cur_pow *= cur_pow;
+            cur_pow = cur_pow.square();
         }
 
         res
     }
+
+    /// Frobenius orbit of the element: a, a^p, a^(p^2), ... until it cycles back to a,
+    /// where p is the characteristic of the field. For the prime field the orbit is just [a].
+    pub fn conjugates(self) -> impl Iterator<Item = FieldElement<'a>> {
+        let characteristic = self.field.characteristic();
+
+        iter::successors(Some(self), move |&cur| {
+            let next = cur.pow_u64(characteristic);
+            (next != self).then_some(next)
+        })
+    }
+
+    /// Same as `self + self`, without the field check.
+    pub fn double(self) -> FieldElement<'a> {
+        let mut val = self.canonical_val() * 2;
+        if val >= self.field.k_modulus {
+            val -= self.field.k_modulus;
+        }
+
+        FieldElement {
+            val,
+            field: self.field,
+        }
+    }
+
+    /// Same as `self * self`, without the field check.
+    pub fn square(self) -> FieldElement<'a> {
+        FieldElement {
+            val: self
+                .field
+                .barrett_reduce(self.canonical_val() as u128 * self.canonical_val() as u128),
+            field: self.field,
+        }
+    }
+
+    /// Value of the element reduced modulo `k_modulus`. Public `val` can be set to any value,
+    /// so every consumer of the value reads it through this method.
+    pub fn canonical_val(&self) -> u64 {
+        if self.val < self.field.k_modulus {
+            self.val
+        } else {
+            self.val % self.field.k_modulus
+        }
+    }
+
+    /// Same as `canonical_val`, but without division and data-dependent branches.
+    /// Barrett estimate of the quotient leaves the remainder below 2 * k_modulus,
+    /// so a single constant-time conditional subtraction completes the reduction.
+    #[cfg(feature = "subtle")]
+    fn ct_canonical_val(&self) -> u64 {
+        let k_modulus = self.field.k_modulus;
+        let quotient = ((self.val as u128 * self.field.barrett_const as u128) >> 64) as u64;
+        let rem = self.val - quotient * k_modulus;
+
+        u64::conditional_select(&rem, &rem.wrapping_sub(k_modulus), !rem.ct_lt(&k_modulus))
+    }
+
+    /// Panics reporting both fields when `self` and `rhs` lay in different fields.
+    fn field_mismatch(&self, action: &str, rhs: &FieldElement) -> ! {
+        panic!(
+            "Elements can't be {} cause they lay in different fields: {} and {}",
+            action, self.field, rhs.field
+        );
+    }
+
+    /// Sum of elements or `None` if they lay in different fields.
+    pub fn checked_add(self, rhs: FieldElement<'a>) -> Option<FieldElement<'a>> {
+        std::ptr::eq(self.field, rhs.field).then(|| self + rhs)
+    }
+
+    /// Difference of elements or `None` if they lay in different fields.
+    pub fn checked_sub(self, rhs: FieldElement<'a>) -> Option<FieldElement<'a>> {
+        std::ptr::eq(self.field, rhs.field).then(|| self - rhs)
+    }
+
+    /// Product of elements or `None` if they lay in different fields.
+    pub fn checked_mul(self, rhs: FieldElement<'a>) -> Option<FieldElement<'a>> {
+        std::ptr::eq(self.field, rhs.field).then(|| self * rhs)
+    }
 }
 
 impl<'a> ops::Add<FieldElement<'a>> for FieldElement<'a> {
@@ -46,11 +164,11 @@ impl<'a> ops::Add<FieldElement<'a>> for FieldElement<'a> {
     fn add(self, rhs: FieldElement<'a>) -> Self::Output {
         if std::ptr::eq(self.field, rhs.field) {
             FieldElement {
-                val: (self.val + rhs.val) % self.field.k_modulus,
+                val: (self.canonical_val() + rhs.canonical_val()) % self.field.k_modulus,
                 field: self.field,
             }
         } else {
-            panic!("Elements can't be summed cause they lay in defferent fields");
+            self.field_mismatch("summed", &rhs);
         }
     }
 }
@@ -60,7 +178,7 @@ impl<'a> ops::Neg for FieldElement<'a> {
 
     fn neg(self) -> FieldElement<'a> {
         self.field
-            .new_element(self.field.zero as i128 - self.val as i128)
+            .new_element(self.field.zero as i128 - self.canonical_val() as i128)
     }
 }
 
@@ -69,9 +187,10 @@ impl<'a> ops::Sub<FieldElement<'a>> for FieldElement<'a> {
 
     fn sub(self, rhs: FieldElement<'a>) -> Self::Output {
         if std::ptr::eq(self.field, rhs.field) {
-            self.field.new_element(self.val as i128 - rhs.val as i128)
+            self.field
+                .new_element(self.canonical_val() as i128 - rhs.canonical_val() as i128)
         } else {
-            panic!("Elements can't be substracted cause they lay in defferent fields");
+            self.field_mismatch("subtracted", &rhs);
         }
     }
 }
@@ -82,11 +201,13 @@ impl<'a> ops::Mul<FieldElement<'a>> for FieldElement<'a> {
     fn mul(self, rhs: FieldElement<'a>) -> Self::Output {
         if std::ptr::eq(self.field, rhs.field) {
             FieldElement {
-                val: (self.val * rhs.val) % self.field.k_modulus,
+                val: self
+                    .field
+                    .barrett_reduce(self.canonical_val() as u128 * rhs.canonical_val() as u128),
                 field: self.field,
             }
         } else {
-            panic!("Elements can't be multiplied cause they lay in defferent fields");
+            self.field_mismatch("multiplied", &rhs);
         }
     }
 }
@@ -94,9 +215,11 @@ impl<'a> ops::Mul<FieldElement<'a>> for FieldElement<'a> {
 impl<'a> ops::MulAssign<FieldElement<'a>> for FieldElement<'a> {
     fn mul_assign(&mut self, rhs: FieldElement<'a>) {
         if std::ptr::eq(self.field, rhs.field) {
-            self.val = (self.val * rhs.val) % self.field.k_modulus;
+            self.val = self
+                .field
+                .barrett_reduce(self.canonical_val() as u128 * rhs.canonical_val() as u128);
         } else {
-            panic!("Elements can't be multiplied cause they lay in defferent fields");
+            self.field_mismatch("multiplied", &rhs);
         }
     }
 }
@@ -104,9 +227,9 @@ impl<'a> ops::MulAssign<FieldElement<'a>> for FieldElement<'a> {
 impl<'a> ops::AddAssign<FieldElement<'a>> for FieldElement<'a> {
     fn add_assign(&mut self, rhs: FieldElement<'a>) {
         if std::ptr::eq(self.field, rhs.field) {
-            self.val = (self.val + rhs.val) % self.field.k_modulus;
+            self.val = (self.canonical_val() + rhs.canonical_val()) % self.field.k_modulus;
         } else {
-            panic!("Elements can't be multiplied cause they lay in defferent fields");
+            self.field_mismatch("summed", &rhs);
         }
     }
 }
@@ -114,10 +237,10 @@ impl<'a> ops::AddAssign<FieldElement<'a>> for FieldElement<'a> {
 impl<'a> ops::SubAssign<FieldElement<'a>> for FieldElement<'a> {
     fn sub_assign(&mut self, rhs: FieldElement<'a>) {
         if std::ptr::eq(self.field, rhs.field) {
-            self.val = (self.val as i128 - rhs.val as i128).rem_euclid(self.field.k_modulus as i128)
-                as u64;
+            self.val = (self.canonical_val() as i128 - rhs.canonical_val() as i128)
+                .rem_euclid(self.field.k_modulus as i128) as u64;
         } else {
-            panic!("Elements can't be multiplied cause they lay in defferent fields");
+            self.field_mismatch("subtracted", &rhs);
         }
     }
 }
@@ -130,14 +253,76 @@ impl<'a> ops::Div<FieldElement<'a>> for FieldElement<'a> {
         if std::ptr::eq(self.field, rhs.field) {
             self * rhs.inverse()
         } else {
-            panic!("Elements can't be divided cause they lay in defferent fields");
+            self.field_mismatch("divided", &rhs);
         }
     }
 }
 
+impl<'a> ops::Add<i128> for FieldElement<'a> {
+    type Output = FieldElement<'a>;
+
+    /// Adds integer reduced modulo `k_modulus` to the element.
+    fn add(self, rhs: i128) -> Self::Output {
+        self + self.field.new_element(rhs)
+    }
+}
+
+impl<'a> ops::Sub<i128> for FieldElement<'a> {
+    type Output = FieldElement<'a>;
+
+    /// Subtracts integer reduced modulo `k_modulus` from the element.
+    fn sub(self, rhs: i128) -> Self::Output {
+        self - self.field.new_element(rhs)
+    }
+}
+
+impl<'a> ops::Mul<i128> for FieldElement<'a> {
+    type Output = FieldElement<'a>;
+
+    /// Multiplies element by integer reduced modulo `k_modulus`.
+    fn mul(self, rhs: i128) -> Self::Output {
+        self * self.field.new_element(rhs)
+    }
+}
+
+impl Default for FieldElement<'static> {
+    /// Zero of the `DEFAULT_FIELD`.
+    fn default() -> FieldElement<'static> {
+        DEFAULT_FIELD.zero()
+    }
+}
+
 impl<'a> cmp::PartialEq<FieldElement<'a>> for FieldElement<'a> {
     fn eq(&self, other: &FieldElement<'a>) -> bool {
-        std::ptr::eq(self.field, other.field) && self.val == other.val
+        std::ptr::eq(self.field, other.field) && self.canonical_val() == other.canonical_val()
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<'a> ConstantTimeEq for FieldElement<'a> {
+    /// Same as `==`, but values are compared in constant time.
+    /// Fields are still compared by address, which is not considered secret.
+    fn ct_eq(&self, other: &FieldElement<'a>) -> Choice {
+        let (lhs, rhs) = (self.ct_canonical_val(), other.ct_canonical_val());
+        Choice::from(std::ptr::eq(self.field, other.field) as u8) & lhs.ct_eq(&rhs)
+    }
+}
+
+impl<'a> iter::Sum<FieldElement<'a>> for FieldElement<'a> {
+    /// Sums non-empty sequence of elements. Use `GaloisField::sum`
+    /// when the sequence may be empty.
+    fn sum<I: Iterator<Item = FieldElement<'a>>>(iter: I) -> Self {
+        iter.reduce(|acc, el| acc + el)
+            .expect("Empty sequence of elements can't be summed without a field, use GaloisField::sum instead")
+    }
+}
+
+impl<'a> iter::Product<FieldElement<'a>> for FieldElement<'a> {
+    /// Multiplies non-empty sequence of elements. Use `GaloisField::product`
+    /// when the sequence may be empty.
+    fn product<I: Iterator<Item = FieldElement<'a>>>(iter: I) -> Self {
+        iter.reduce(|acc, el| acc * el)
+            .expect("Empty sequence of elements can't be multiplied without a field, use GaloisField::product instead")
     }
 }
 
@@ -146,7 +331,7 @@ mod tests {
     use super::FieldElement;
     use crate::field::GaloisField;
 
-    const FIELD: GaloisField = crate::galois_field!();
+    static FIELD: GaloisField = crate::galois_field!();
 
     #[test]
     fn inverse_test() {
@@ -181,6 +366,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn invert_or_zero_test() {
+        static SMALL_FIELD: GaloisField = GaloisField::new(17, 3);
+
+        assert_eq!(FIELD.zero().invert_or_zero(), FIELD.zero());
+        assert_eq!(SMALL_FIELD.zero().invert_or_zero(), SMALL_FIELD.zero());
+
+        for val in [1, 10, 660, 3221225472, 1039791324] {
+            let el = FIELD.new_element(val);
+            assert_eq!(el.invert_or_zero(), el.inverse());
+        }
+        for val in 1..17 {
+            let el = SMALL_FIELD.new_element(val);
+            assert_eq!(el.invert_or_zero() * el, SMALL_FIELD.one());
+        }
+    }
+
     #[test]
     fn pow_test() {
         let test_data = [
@@ -208,7 +410,23 @@ mod tests {
 
         for (el_val, pow, pow_val) in test_data {
             assert_eq!(FIELD.new_element(el_val).pow(pow).val, pow_val);
+            assert_eq!(FIELD.new_element(el_val).pow_u64(pow as u64).val, pow_val);
+        }
+    }
+
+    #[test]
+    fn pow_u64_test() {
+        let order = FIELD.k_modulus - 1;
+
+        for val in [1, 5, 17, 1039791324, -2437383495] {
+            let el = FIELD.new_element(val);
+            assert_eq!(el.pow_u64(order), FIELD.one());
+            assert_eq!(el.pow_u64(order + 5), el.pow(5));
+            assert_eq!(el.pow_u64(FIELD.k_modulus - 2), el.inverse());
+            assert_eq!(el.pow_u64(u64::MAX), el.pow_u64(u64::MAX % order));
         }
+        assert_eq!(FIELD.zero().pow_u64(0), FIELD.one());
+        assert_eq!(FIELD.zero().pow_u64(order), FIELD.zero());
     }
 
     #[test]
@@ -369,6 +587,252 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn ct_eq_test() {
+        use subtle::ConstantTimeEq;
+
+        static SMALL_FIELD: GaloisField = GaloisField::new(17, 3);
+        let vals = [0, 1, 2, 16, 17, 1238909831, 3221225472];
+
+        for &lhs_val in vals.iter() {
+            for &rhs_val in vals.iter() {
+                let (lhs, rhs) = (FIELD.new_element(lhs_val), FIELD.new_element(rhs_val));
+                let other = SMALL_FIELD.new_element(rhs_val);
+
+                assert_eq!(bool::from(lhs.ct_eq(&rhs)), lhs == rhs);
+                assert_eq!(bool::from(lhs.ct_eq(&other)), lhs == other);
+                assert!(!bool::from(lhs.ct_eq(&other)));
+            }
+        }
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn ct_canonical_val_test() {
+        use subtle::ConstantTimeEq;
+
+        for (k_modulus, generator_val) in [(2, 1), (17, 3), (3221225473, 5), (4294967291, 2)] {
+            let field = GaloisField::new(k_modulus, generator_val);
+            let vals = [
+                0,
+                1,
+                k_modulus - 1,
+                k_modulus,
+                2 * k_modulus - 1,
+                1 << 63,
+                u64::MAX,
+            ];
+
+            for val in vals {
+                let el = FieldElement { val, field: &field };
+                let canonical = field.new_element((val % k_modulus) as i128);
+
+                assert_eq!(el.ct_canonical_val(), el.canonical_val());
+                assert!(bool::from(el.ct_eq(&canonical)));
+            }
+        }
+    }
+
+    #[test]
+    fn integer_ops_test() {
+        let test_data = [0, 1, 3, -1, 3221225473, -2437383495, i128::MAX, i128::MIN];
+
+        for el_val in [0, 17, 1238909831, 3221225472] {
+            let el = FIELD.new_element(el_val);
+
+            for num in test_data {
+                let num_el = FIELD.new_element(num);
+                assert_eq!(el + num, el + num_el);
+                assert_eq!(el - num, el - num_el);
+                assert_eq!(el * num, el * num_el);
+            }
+        }
+
+        assert_eq!(FIELD.new_element(5) + 3, FIELD.new_element(8));
+        assert_eq!(FIELD.new_element(5) - 7, FIELD.new_element(-2));
+        assert_eq!(FIELD.new_element(5) * 3, FIELD.new_element(15));
+    }
+
+    #[test]
+    fn checked_ops_test() {
+        static OTHER_FIELD: GaloisField = crate::galois_field!();
+
+        let lhs = FIELD.new_element(-2674122163);
+        let rhs = FIELD.new_element(2576410027);
+        let other = OTHER_FIELD.new_element(2576410027);
+
+        assert_eq!(lhs.checked_add(rhs), Some(lhs + rhs));
+        assert_eq!(lhs.checked_sub(rhs), Some(lhs - rhs));
+        assert_eq!(lhs.checked_mul(rhs), Some(lhs * rhs));
+
+        assert_eq!(lhs.checked_add(other), None);
+        assert_eq!(lhs.checked_sub(other), None);
+        assert_eq!(other.checked_mul(lhs), None);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Elements can't be summed cause they lay in different fields: GF(3221225473) with generator 5 and GF(17) with generator 3"
+    )]
+    fn field_mismatch_test() {
+        static OTHER_FIELD: GaloisField = GaloisField::new(17, 3);
+
+        let _ = FIELD.new_element(10) + OTHER_FIELD.new_element(10);
+    }
+
+    #[test]
+    fn neg_identities_test() {
+        static SMALL_FIELD: GaloisField = GaloisField::new(17, 3);
+
+        assert_eq!(-FIELD.zero(), FIELD.zero());
+        assert_eq!(-SMALL_FIELD.zero(), SMALL_FIELD.zero());
+
+        let mut val = 1i128;
+        let elements = (0..1000)
+            .map(|_| {
+                val = val * 6364136223846793005 % 3221225473 + 1;
+                FIELD.new_element(val)
+            })
+            .chain([0, 1, -1, 1610612736, 1610612737].map(|val| FIELD.new_element(val)))
+            .chain((0..17).map(|val| SMALL_FIELD.new_element(val)));
+
+        for el in elements {
+            assert_eq!(-(-el), el);
+            assert_eq!(el - el, el.field.zero());
+            assert_eq!(el + -el, el.field.zero());
+            assert!(el.field.contains(&-el));
+        }
+    }
+
+    #[test]
+    fn conjugates_test() {
+        for val in [0, 1, 5, -1, 1238909831] {
+            let el = FIELD.new_element(val);
+            let orbit = el.conjugates().collect::<Vec<_>>();
+
+            assert_eq!(orbit, vec![el]);
+            assert_eq!(FIELD.extension_degree() as usize % orbit.len(), 0);
+        }
+    }
+
+    #[test]
+    fn from_val_test() {
+        let test_data = [
+            (0, 0),
+            (5, 5),
+            (3221225472, 3221225472),
+            (3221225473, 0),
+            (u64::MAX, 1789569708),
+        ];
+
+        for (val, res) in test_data {
+            assert_eq!(FieldElement::from_val(val, &FIELD), FIELD.new_element(res));
+        }
+        for val in [0, 1, 3221225472] {
+            let el = unsafe { FieldElement::from_val_unchecked(val, &FIELD) };
+            assert_eq!(el, FIELD.new_element(val as i128));
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(
+        expected = "Value 3221225473 is out of range of GF(3221225473) with generator 5."
+    )]
+    fn from_val_unchecked_out_of_range_test() {
+        unsafe { FieldElement::from_val_unchecked(3221225473, &FIELD) };
+    }
+
+    #[test]
+    fn double_square_test() {
+        let test_data = [0, 1, 2, 1610612736, 1610612737, 3221225472, 1238909831];
+
+        for val in test_data {
+            let el = FIELD.new_element(val);
+            assert_eq!(el.double(), el + el);
+            assert_eq!(el.square(), el * el);
+        }
+    }
+
+    #[test]
+    fn denormalized_arithmetic_test() {
+        let test_data = [
+            (FIELD.k_modulus + 3, 3),
+            (2 * FIELD.k_modulus - 1, 3221225472),
+            (u64::MAX, 1789569708),
+        ];
+        let rhs = FIELD.new_element(1238909831);
+
+        for (val, canonical_val) in test_data {
+            let mut el = FIELD.new_element(0);
+            el.val = val;
+            let canonical = FIELD.new_element(canonical_val);
+
+            assert_eq!(el, canonical);
+            assert_eq!(el.canonical_val(), canonical_val as u64);
+            assert_eq!((el * rhs).val, (canonical * rhs).val);
+            assert_eq!(el + rhs, canonical + rhs);
+            assert_eq!(rhs + el, canonical + rhs);
+            assert_eq!(el - rhs, canonical - rhs);
+            assert_eq!(rhs - el, rhs - canonical);
+            assert_eq!(el * rhs, canonical * rhs);
+            assert_eq!(rhs * el, canonical * rhs);
+            assert_eq!(el / rhs, canonical / rhs);
+            assert_eq!(rhs / el, rhs / canonical);
+            assert_eq!(-el, -canonical);
+            assert_eq!(el.double(), canonical.double());
+            assert_eq!(el.square(), canonical.square());
+            assert_eq!(el.pow(7), canonical.pow(7));
+
+            let mut acc = rhs;
+            acc += el;
+            acc *= el;
+            acc -= el;
+            assert_eq!(acc, (rhs + canonical) * canonical - canonical);
+        }
+    }
+
+    #[test]
+    fn iter_sum_test() {
+        let elements = crate::utils::nums_to_elements(
+            vec![
+                -2674122163i64,
+                2576410027,
+                951208061,
+                -620302777,
+                3130882677,
+            ],
+            &FIELD,
+        );
+        let expected = elements.iter().fold(FIELD.zero(), |acc, &el| acc + el);
+
+        assert_eq!(elements.iter().copied().sum::<FieldElement>(), expected);
+    }
+
+    #[test]
+    fn iter_product_test() {
+        let elements = crate::utils::nums_to_elements(
+            vec![
+                -3217314898i64,
+                2848844329,
+                3062075925,
+                -700399855,
+                264996178,
+            ],
+            &FIELD,
+        );
+        let expected = elements.iter().fold(FIELD.one(), |acc, &el| acc * el);
+
+        assert_eq!(elements.iter().copied().product::<FieldElement>(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Empty sequence of elements can't be summed without a field")]
+    fn iter_sum_empty_test() {
+        let _ = Vec::<FieldElement>::new().into_iter().sum::<FieldElement>();
+    }
+
     #[test]
     fn expr_test() {
         let element = FIELD.new_element(2).pow(30) * FIELD.new_element(3) + FIELD.new_element(1);
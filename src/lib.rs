@@ -0,0 +1,12 @@
+pub mod barycentric_interpolator;
+pub mod bivariate_polynomial;
+pub mod error;
+pub mod field;
+pub mod field_element;
+pub mod ntt;
+pub mod owned;
+pub mod polynomial;
+pub mod raw_polynomial;
+pub mod sparse_polynomial;
+pub mod utils;
+pub mod welch_berlekamp;
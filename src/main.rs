@@ -1,12 +1,7 @@
-pub mod field;
-pub mod field_element;
-pub mod polynomial;
-pub mod utils;
-
 fn main() {
     println!("Hello, world!");
 
-    let field = crate::galois_field!();
+    let field = reed_solomon::galois_field!();
 
     let el1 = field.new_element(10);
     let el2 = field.new_element(12);
@@ -0,0 +1,210 @@
+use crate::field::GaloisField;
+use crate::field_element::FieldElement;
+use crate::utils;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Minimal transform length whose butterfly stages are split across threads.
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 1 << 14;
+
+/// Number theoretic transform of `values` performed in place: the element at index i
+/// is replaced by sum(values[j] * w^(i*j)), where w is the primitive root of unity of
+/// order `values.len()`. Length should be a power of 2 that divides `k_modulus - 1`.
+/// Uses iterative Cooley–Tukey butterflies, so the stack depth doesn't depend on the length.
+pub fn ntt(values: &mut [FieldElement]) {
+    if let Some(field) = check_values(values) {
+        let root = root_of_unity(field, values.len());
+        transform(values, root);
+    }
+}
+
+/// Inverse of `ntt` performed in place, scaled by the inverse of the length.
+pub fn intt(values: &mut [FieldElement]) {
+    if let Some(field) = check_values(values) {
+        let root = root_of_unity(field, values.len()).inverse();
+        transform(values, root);
+
+        let scale = field.new_element(values.len() as i128).inverse();
+        for el in values.iter_mut() {
+            *el *= scale;
+        }
+    }
+}
+
+fn transform<'a>(values: &mut [FieldElement<'a>], root: FieldElement<'a>) {
+    let n = values.len();
+    let twiddles = root.field.powers_of(root, n / 2).collect::<Vec<_>>();
+
+    utils::bit_reverse_permute(values);
+
+    let mut len = 2;
+    while len <= n {
+        #[cfg(feature = "rayon")]
+        if n >= PARALLEL_THRESHOLD {
+            par_butterfly_stage(values, &twiddles, len);
+            len *= 2;
+            continue;
+        }
+
+        butterfly_stage(values, &twiddles, len);
+        len *= 2;
+    }
+}
+
+/// Combines pairs of transforms of length `len / 2` into transforms of length `len`.
+fn butterfly_stage<'a>(values: &mut [FieldElement<'a>], twiddles: &[FieldElement<'a>], len: usize) {
+    let half = len / 2;
+    let stride = values.len() / len;
+
+    for chunk in values.chunks_exact_mut(len) {
+        let (evens, odds) = chunk.split_at_mut(half);
+        for (j, (even, odd)) in evens.iter_mut().zip(odds.iter_mut()).enumerate() {
+            butterfly(even, odd, twiddles[j * stride]);
+        }
+    }
+}
+
+/// Same as `butterfly_stage`, but independent butterflies are performed by the rayon thread pool.
+/// Twiddles are only read, so they are shared between threads as is.
+#[cfg(feature = "rayon")]
+fn par_butterfly_stage<'a>(
+    values: &mut [FieldElement<'a>],
+    twiddles: &[FieldElement<'a>],
+    len: usize,
+) {
+    let half = len / 2;
+    let stride = values.len() / len;
+
+    values.par_chunks_exact_mut(len).for_each(|chunk| {
+        let (evens, odds) = chunk.split_at_mut(half);
+        evens
+            .par_iter_mut()
+            .zip(odds.par_iter_mut())
+            .enumerate()
+            .for_each(|(j, (even, odd))| butterfly(even, odd, twiddles[j * stride]));
+    });
+}
+
+fn butterfly<'a>(
+    even: &mut FieldElement<'a>,
+    odd: &mut FieldElement<'a>,
+    twiddle: FieldElement<'a>,
+) {
+    let product = *odd * twiddle;
+    *odd = *even - product;
+    *even += product;
+}
+
+/// Primitive root of unity of order `n` calculated as generator^((k_modulus - 1) / n).
+fn root_of_unity(field: &GaloisField, n: usize) -> FieldElement {
+    let order = field.k_modulus - 1;
+
+    if !n.is_power_of_two() || order % n as u64 != 0 {
+        panic!(
+            "Impossible to perform NTT of length {} over {}: length should be a power of 2 that divides the multiplicative group order.",
+            n, field
+        );
+    }
+
+    field.generator().pow_u64(order / n as u64)
+}
+
+/// Returns the field of `values`, or `None` if there are no values to transform.
+fn check_values<'a>(values: &[FieldElement<'a>]) -> Option<&'a GaloisField> {
+    let field = values.first()?.field;
+
+    for el in values.iter() {
+        if !std::ptr::eq(el.field, field) {
+            panic!("Not all elements are lay in the same field.");
+        }
+    }
+
+    Some(field)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rayon")]
+    use super::{butterfly_stage, par_butterfly_stage};
+    use super::{intt, ntt};
+    use crate::field::GaloisField;
+    use crate::polynomial::Polynomial;
+    use crate::utils;
+
+    static FIELD: GaloisField = crate::galois_field!();
+
+    #[test]
+    fn ntt_test() {
+        for n in [1, 2, 4, 8, 64, 256] {
+            let values =
+                utils::nums_to_elements((0..n as i128).map(|i| i * i * 7919 - 104729), &FIELD);
+            let root = FIELD
+                .generator()
+                .pow(((FIELD.k_modulus - 1) / n as u64) as u32);
+            let poly = Polynomial::new(values.clone(), &FIELD, "x");
+            let res = FIELD
+                .powers_of(root, n)
+                .map(|x| poly.evaluate(x))
+                .collect::<Vec<_>>();
+
+            let mut transformed = values.clone();
+            ntt(&mut transformed);
+            assert_eq!(transformed, res);
+
+            intt(&mut transformed);
+            assert_eq!(transformed, values);
+        }
+    }
+
+    #[test]
+    fn ntt_round_trip_test() {
+        for n in [16, 1 << 12, 1 << 16] {
+            let values = utils::nums_to_elements((0..n as i128).map(|i| 3 * i + 1), &FIELD);
+            let mut transformed = values.clone();
+
+            ntt(&mut transformed);
+            assert_ne!(transformed, values);
+            intt(&mut transformed);
+            assert_eq!(transformed, values);
+        }
+
+        let mut empty = vec![];
+        ntt(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_butterfly_stage_test() {
+        for n in [2, 64, 1 << 12, 1 << 16] {
+            let root = FIELD.generator().pow_u64((FIELD.k_modulus - 1) / n as u64);
+            let twiddles = FIELD.powers_of(root, n / 2).collect::<Vec<_>>();
+            let mut serial =
+                utils::nums_to_elements((0..n as i128).map(|i| i * i * 7919 - 104729), &FIELD);
+            let mut parallel = serial.clone();
+
+            let mut len = 2;
+            while len <= n {
+                butterfly_stage(&mut serial, &twiddles, len);
+                par_butterfly_stage(&mut parallel, &twiddles, len);
+                assert_eq!(parallel, serial);
+                len *= 2;
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Impossible to perform NTT of length 6")]
+    fn ntt_invalid_len_test() {
+        ntt(&mut utils::nums_to_elements(vec![1, 2, 3, 4, 5, 6], &FIELD));
+    }
+
+    #[test]
+    #[should_panic(expected = "Impossible to perform NTT of length 32")]
+    fn ntt_unsupported_len_test() {
+        static SMALL_FIELD: GaloisField = GaloisField::new(17, 3);
+        ntt(&mut utils::nums_to_elements(vec![1; 32], &SMALL_FIELD));
+    }
+}
@@ -0,0 +1,356 @@
+use crate::field::GaloisField;
+use crate::field_element::FieldElement;
+use crate::polynomial::Polynomial;
+
+use std::sync::Arc;
+use std::{cmp, ops};
+
+/// Field element that shares ownership of its field instead of borrowing it,
+/// so it can be stored in long-lived structs and returned from functions.
+/// Elements are considered to lay in the same field only if they share the same `Arc`.
+#[derive(Debug, Clone)]
+pub struct OwnedElement {
+    pub val: u64,
+    pub field: Arc<GaloisField>,
+}
+
+impl OwnedElement {
+    pub fn new(element_val: i128, field: &Arc<GaloisField>) -> OwnedElement {
+        OwnedElement::from_element(field.new_element(element_val), field)
+    }
+
+    /// Takes ownership over the borrowed element that lays in the `field`.
+    pub fn from_element(element: FieldElement, field: &Arc<GaloisField>) -> OwnedElement {
+        if !std::ptr::eq(element.field, Arc::as_ptr(field)) {
+            panic!(
+                "Element lays in different field: {} instead of {}.",
+                element.field, field
+            );
+        }
+
+        OwnedElement {
+            val: element.canonical_val(),
+            field: Arc::clone(field),
+        }
+    }
+
+    /// Borrows the element as `FieldElement` bound to the lifetime of `self`.
+    pub fn as_element(&self) -> FieldElement<'_> {
+        FieldElement {
+            val: self.val,
+            field: &self.field,
+        }
+    }
+
+    pub fn inverse(&self) -> OwnedElement {
+        self.lift(self.as_element().inverse())
+    }
+
+    pub fn pow(&self, n: u32) -> OwnedElement {
+        self.lift(self.as_element().pow(n))
+    }
+
+    fn lift(&self, element: FieldElement) -> OwnedElement {
+        OwnedElement {
+            val: element.canonical_val(),
+            field: Arc::clone(&self.field),
+        }
+    }
+}
+
+impl cmp::PartialEq<OwnedElement> for OwnedElement {
+    fn eq(&self, other: &OwnedElement) -> bool {
+        self.as_element() == other.as_element()
+    }
+}
+
+impl ops::Add<OwnedElement> for OwnedElement {
+    type Output = OwnedElement;
+
+    fn add(self, rhs: OwnedElement) -> Self::Output {
+        self.lift(self.as_element() + rhs.as_element())
+    }
+}
+
+impl ops::Neg for OwnedElement {
+    type Output = OwnedElement;
+
+    fn neg(self) -> Self::Output {
+        self.lift(-self.as_element())
+    }
+}
+
+impl ops::Sub<OwnedElement> for OwnedElement {
+    type Output = OwnedElement;
+
+    fn sub(self, rhs: OwnedElement) -> Self::Output {
+        self.lift(self.as_element() - rhs.as_element())
+    }
+}
+
+impl ops::Mul<OwnedElement> for OwnedElement {
+    type Output = OwnedElement;
+
+    fn mul(self, rhs: OwnedElement) -> Self::Output {
+        self.lift(self.as_element() * rhs.as_element())
+    }
+}
+
+impl ops::Div<OwnedElement> for OwnedElement {
+    type Output = OwnedElement;
+
+    fn div(self, rhs: OwnedElement) -> Self::Output {
+        self.lift(self.as_element() / rhs.as_element())
+    }
+}
+
+/// Polynomial that shares ownership of its field instead of borrowing it (see `OwnedElement`).
+/// # Arguments
+/// * `coeffs` - values of the polynomial coefficients, listed in ascending order of terms powers
+/// * `field` - shared handle of the finite field that provides polynomial coefficients
+/// * `var` - polynomial variable designation
+#[derive(Debug, Clone)]
+pub struct OwnedPolynomial {
+    coeffs: Vec<u64>,
+    field: Arc<GaloisField>,
+    var: String,
+}
+
+impl OwnedPolynomial {
+    pub fn new(coeffs: Vec<OwnedElement>, field: &Arc<GaloisField>, var: &str) -> OwnedPolynomial {
+        let coeffs = coeffs
+            .iter()
+            .map(|el| {
+                if !Arc::ptr_eq(&el.field, field) {
+                    panic!(
+                        "Not all coefficients of the constructing
+                    polynomial are lay in the same field!"
+                    );
+                }
+                el.as_element()
+            })
+            .collect();
+
+        OwnedPolynomial::from_polynomial(&Polynomial::new(coeffs, field, var), field)
+    }
+
+    /// Takes ownership over the borrowed polynomial built over the `field`.
+    pub fn from_polynomial(poly: &Polynomial, field: &Arc<GaloisField>) -> OwnedPolynomial {
+        if !std::ptr::eq(poly.field(), Arc::as_ptr(field)) {
+            panic!(
+                "Polynomial is built over different field: {} instead of {}.",
+                poly.field(),
+                field
+            );
+        }
+
+        let mut coeffs = vec![field.zero; poly.degree().map_or(0, |deg| deg + 1)];
+        for (pow, coef) in poly.terms() {
+            coeffs[pow] = coef.canonical_val();
+        }
+
+        OwnedPolynomial {
+            coeffs,
+            field: Arc::clone(field),
+            var: poly.var().to_string(),
+        }
+    }
+
+    /// Borrows the polynomial as `Polynomial` bound to the lifetime of `self`.
+    pub fn as_polynomial(&self) -> Polynomial<'_> {
+        Polynomial::new(
+            self.coeffs
+                .iter()
+                .map(|&val| FieldElement {
+                    val,
+                    field: &self.field,
+                })
+                .collect(),
+            &self.field,
+            &self.var,
+        )
+    }
+
+    pub fn field(&self) -> &Arc<GaloisField> {
+        &self.field
+    }
+
+    pub fn var(&self) -> &str {
+        &self.var
+    }
+
+    /// Degree of the polynomial, `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn evaluate(&self, x: &OwnedElement) -> OwnedElement {
+        x.lift(self.as_polynomial().evaluate(x.as_element()))
+    }
+
+    pub fn div_rem(&self, rhs: &OwnedPolynomial) -> (OwnedPolynomial, OwnedPolynomial) {
+        let (quot, rem) = self.as_polynomial().div_rem(&rhs.as_polynomial());
+        (self.lift(&quot), self.lift(&rem))
+    }
+
+    fn lift(&self, poly: &Polynomial) -> OwnedPolynomial {
+        OwnedPolynomial::from_polynomial(poly, &self.field)
+    }
+}
+
+impl cmp::PartialEq<OwnedPolynomial> for OwnedPolynomial {
+    fn eq(&self, other: &OwnedPolynomial) -> bool {
+        self.as_polynomial() == other.as_polynomial()
+    }
+}
+
+impl ops::Add<OwnedPolynomial> for OwnedPolynomial {
+    type Output = OwnedPolynomial;
+
+    fn add(self, rhs: OwnedPolynomial) -> Self::Output {
+        self.lift(&(self.as_polynomial() + rhs.as_polynomial()))
+    }
+}
+
+impl ops::Neg for OwnedPolynomial {
+    type Output = OwnedPolynomial;
+
+    fn neg(self) -> Self::Output {
+        self.lift(&-self.as_polynomial())
+    }
+}
+
+impl ops::Sub<OwnedPolynomial> for OwnedPolynomial {
+    type Output = OwnedPolynomial;
+
+    fn sub(self, rhs: OwnedPolynomial) -> Self::Output {
+        self.lift(&(self.as_polynomial() - rhs.as_polynomial()))
+    }
+}
+
+impl ops::Mul<OwnedPolynomial> for OwnedPolynomial {
+    type Output = OwnedPolynomial;
+
+    fn mul(self, rhs: OwnedPolynomial) -> Self::Output {
+        self.lift(&(self.as_polynomial() * rhs.as_polynomial()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OwnedElement, OwnedPolynomial};
+    use crate::field::GaloisField;
+    use crate::polynomial::Polynomial;
+    use crate::utils;
+
+    use itertools::izip;
+    use std::sync::Arc;
+
+    static FIELD: GaloisField = crate::galois_field!();
+
+    fn owned_field() -> Arc<GaloisField> {
+        Arc::new(crate::galois_field!())
+    }
+
+    /// Values of the coefficients, comparable between polynomials over different fields.
+    fn coeff_vals(poly: &Polynomial) -> Vec<u64> {
+        poly.as_ref().iter().map(|el| el.val).collect()
+    }
+
+    fn owned_poly(nums: Vec<i128>, field: &Arc<GaloisField>) -> OwnedPolynomial {
+        let coeffs = nums
+            .into_iter()
+            .map(|num| OwnedElement::new(num, field))
+            .collect();
+        OwnedPolynomial::new(coeffs, field, "x")
+    }
+
+    #[test]
+    fn element_arithmetic_test() {
+        let field = owned_field();
+        let lhs_data = [0, 1, 17, 3221225472, 1238909831];
+        let rhs_data = [5, 3221225472, 2, 3221225472, 982718];
+
+        for (lhs_raw, rhs_raw) in izip!(lhs_data, rhs_data) {
+            let (lhs, rhs) = (
+                OwnedElement::new(lhs_raw, &field),
+                OwnedElement::new(rhs_raw, &field),
+            );
+            let (lhs_ref, rhs_ref) = (FIELD.new_element(lhs_raw), FIELD.new_element(rhs_raw));
+
+            assert_eq!((lhs.clone() + rhs.clone()).val, (lhs_ref + rhs_ref).val);
+            assert_eq!((lhs.clone() - rhs.clone()).val, (lhs_ref - rhs_ref).val);
+            assert_eq!((lhs.clone() * rhs.clone()).val, (lhs_ref * rhs_ref).val);
+            assert_eq!((lhs.clone() / rhs.clone()).val, (lhs_ref / rhs_ref).val);
+            assert_eq!((-lhs.clone()).val, (-lhs_ref).val);
+            assert_eq!(lhs.pow(7).val, lhs_ref.pow(7).val);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn element_different_fields_test() {
+        let _ = OwnedElement::new(1, &owned_field()) + OwnedElement::new(1, &owned_field());
+    }
+
+    #[test]
+    fn polynomial_arithmetic_test() {
+        let field = owned_field();
+        let lhs_data = [
+            vec![],
+            vec![1, 2, 3],
+            vec![-1, 0, 0, 0, 1],
+            vec![17, 0, -3, 2821],
+        ];
+        let rhs_data = [
+            vec![3, 4],
+            vec![5, 6, 7, 8],
+            vec![-1, 1],
+            vec![0, 0, 0, -2821],
+        ];
+
+        for (lhs_raw, rhs_raw) in izip!(lhs_data, rhs_data) {
+            let lhs = owned_poly(lhs_raw.clone(), &field);
+            let rhs = owned_poly(rhs_raw.clone(), &field);
+            let lhs_ref = Polynomial::new(utils::nums_to_elements(lhs_raw, &FIELD), &FIELD, "x");
+            let rhs_ref = Polynomial::new(utils::nums_to_elements(rhs_raw, &FIELD), &FIELD, "x");
+            let (quot, rem) = lhs.div_rem(&rhs);
+            let (quot_ref, rem_ref) = lhs_ref.div_rem(&rhs_ref);
+
+            assert_eq!(
+                coeff_vals(&(lhs.clone() + rhs.clone()).as_polynomial()),
+                coeff_vals(&(lhs_ref.clone() + rhs_ref.clone()))
+            );
+            assert_eq!(
+                coeff_vals(&(lhs.clone() - rhs.clone()).as_polynomial()),
+                coeff_vals(&(lhs_ref.clone() - rhs_ref.clone()))
+            );
+            assert_eq!(
+                coeff_vals(&(lhs.clone() * rhs.clone()).as_polynomial()),
+                coeff_vals(&(lhs_ref.clone() * rhs_ref.clone()))
+            );
+            assert_eq!(
+                coeff_vals(&(-lhs.clone()).as_polynomial()),
+                coeff_vals(&-lhs_ref.clone())
+            );
+            assert_eq!(coeff_vals(&quot.as_polynomial()), coeff_vals(&quot_ref));
+            assert_eq!(coeff_vals(&rem.as_polynomial()), coeff_vals(&rem_ref));
+            assert_eq!(quot * rhs + rem, lhs);
+        }
+    }
+
+    #[test]
+    fn returned_from_function_test() {
+        fn build() -> (OwnedPolynomial, OwnedElement) {
+            let field = owned_field();
+            (
+                owned_poly(vec![1, 2, 3], &field),
+                OwnedElement::new(2, &field),
+            )
+        }
+
+        let (poly, x) = build();
+        assert_eq!(poly.degree(), Some(2));
+        assert_eq!(poly.evaluate(&x), OwnedElement::new(17, poly.field()));
+    }
+}
@@ -1,9 +1,19 @@
+use crate::error::{ParseError, RsError};
 use crate::field::GaloisField;
 use crate::field_element::FieldElement;
+use crate::sparse_polynomial::SparsePolynomial;
 use crate::utils;
 
 use std::cmp;
-use std::ops;
+use std::collections::HashSet;
+use std::{fmt, ops};
+
+/// Byte order of the coefficient symbols in the binary representation of polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    Little,
+    Big,
+}
 
 /// Polynomial above some finite field `field`.
 /// # Arguments
@@ -48,14 +58,64 @@ impl<'a> Polynomial<'a> {
         }
     }
 
-    pub fn from(coeffs: Vec<FieldElement<'a>>, other: &'a Polynomial) -> Polynomial<'a> {
+    /// Same as `new`, but returns `RsError::DegreeTooLarge` if the degree of the polynomial
+    /// (without trailing zero coefficients) exceeds `max_deg`.
+    pub fn new_bounded(
+        coeffs: Vec<FieldElement<'a>>,
+        field: &'a GaloisField,
+        var: &str,
+        max_deg: usize,
+    ) -> Result<Polynomial<'a>, RsError> {
+        match coeffs
+            .iter()
+            .rposition(|el| el.canonical_val() != field.zero)
+        {
+            Some(deg) if deg > max_deg => Err(RsError::DegreeTooLarge { deg, max_deg }),
+            _ => Ok(Polynomial::new(coeffs, field, var)),
+        }
+    }
+
+    pub fn from(coeffs: Vec<FieldElement<'a>>, other: &Polynomial<'a>) -> Polynomial<'a> {
         Polynomial::new(coeffs, other.field, &other.var)
     }
 
-    pub fn empty(other: &'a Polynomial) -> Polynomial<'a> {
+    pub fn empty(other: &Polynomial<'a>) -> Polynomial<'a> {
         Polynomial::new(vec![], other.field, &other.var)
     }
 
+    /// Parses polynomial from the comma-separated list of its coefficients,
+    /// listed in ascending order of terms powers (see `GaloisField::parse_element`).
+    pub fn parse(s: &str, field: &'a GaloisField, var: &str) -> Result<Polynomial<'a>, ParseError> {
+        let coeffs = if s.trim().is_empty() {
+            vec![]
+        } else {
+            s.split(',')
+                .map(|coef| field.parse_element(coef))
+                .collect::<Result<Vec<FieldElement<'a>>, ParseError>>()?
+        };
+
+        Ok(Polynomial::new(coeffs, field, var))
+    }
+
+    /// Builds polynomial from the byte sequence in which every coefficient is stored as
+    /// a symbol of the minimal width that fits any element of the field, in the given byte order.
+    /// Coefficients are listed in ascending order of terms powers and reduced modulo `k_modulus`.
+    pub fn try_from_bytes(
+        bytes: &[u8],
+        endianness: Endianness,
+        field: &'a GaloisField,
+        var: &str,
+    ) -> Result<Polynomial<'a>, ParseError> {
+        let coeffs = field.elements_from_bytes(bytes, endianness)?;
+
+        Ok(Polynomial::new(coeffs, field, var))
+    }
+
+    /// Serializes coefficients of the polynomial in the format read by `try_from_bytes`.
+    pub fn to_bytes(&self, endianness: Endianness) -> Vec<u8> {
+        self.field.elements_to_bytes(&self.coeffs, endianness)
+    }
+
     pub fn get_field_ptr(coeffs: &[FieldElement]) -> *const GaloisField {
         match coeffs.first() {
             Some(element) => element.field as *const _,
@@ -63,16 +123,89 @@ impl<'a> Polynomial<'a> {
         }
     }
 
+    pub fn field(&self) -> &'a GaloisField {
+        self.field
+    }
+
+    pub fn var(&self) -> &str {
+        &self.var
+    }
+
     pub fn deg(&self) -> i64 {
         self.coeffs.len() as i64 - 1
     }
 
+    /// Degree of the polynomial, `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
     pub fn non_empty(&self) -> bool {
         !self.coeffs.is_empty()
     }
 
-    /// Function composition operation on two polynomials.
-    pub fn compose(&self, rhs: Polynomial<'a>) -> Polynomial {
+    /// Iterates over non-zero terms of the polynomial as `(power, coefficient)`
+    /// pairs in ascending order of terms powers.
+    pub fn terms(&self) -> impl DoubleEndedIterator<Item = (usize, FieldElement<'a>)> + '_ {
+        self.coeffs
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, coef)| coef.canonical_val() != coef.field.zero)
+    }
+
+    /// Iterates over non-zero terms of the polynomial as `(power, coefficient)`
+    /// pairs in descending order of terms powers, same as reversed `terms()`.
+    pub fn terms_descending(&self) -> impl Iterator<Item = (usize, FieldElement<'a>)> + '_ {
+        self.terms().rev()
+    }
+
+    /// Total order of polynomials for deterministic sorting: polynomials are compared
+    /// by degree first (the zero polynomial is the least), then by values of coefficients
+    /// starting from the highest power. Not related to any algebraic structure of the ring.
+    pub fn cmp_canonical(&self, other: &Polynomial<'a>) -> cmp::Ordering {
+        self.deg().cmp(&other.deg()).then_with(|| {
+            self.coeffs
+                .iter()
+                .rev()
+                .map(|el| el.canonical_val())
+                .cmp(other.coeffs.iter().rev().map(|el| el.canonical_val()))
+        })
+    }
+
+    /// Exports polynomial as JSON object of the form
+    /// `{"var": "x", "coeffs": [..], "deg": n}` for debug logging.
+    /// Coefficients are listed in ascending order of terms powers.
+    pub fn to_debug_json(&self) -> String {
+        let mut var = String::with_capacity(self.var.len());
+        for c in self.var.chars() {
+            match c {
+                '"' => var.push_str("\\\""),
+                '\\' => var.push_str("\\\\"),
+                c if c.is_control() => var.push_str(&format!("\\u{:04x}", c as u32)),
+                c => var.push(c),
+            }
+        }
+
+        let coeffs = self
+            .coeffs
+            .iter()
+            .map(|coef| coef.canonical_val().to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        format!(
+            "{{\"var\": \"{}\", \"coeffs\": [{}], \"deg\": {}}}",
+            var,
+            coeffs,
+            self.deg()
+        )
+    }
+
+    /// Function composition operation on two polynomials, self(rhs(x)).
+    /// Composition of the zero polynomial with anything is the zero polynomial,
+    /// composition of anything with the zero polynomial is the constant term of `self`.
+    pub fn compose(&self, rhs: Polynomial<'a>) -> Polynomial<'a> {
         let mut res = Polynomial::empty(self);
 
         for &coef in self.coeffs.iter().rev() {
@@ -82,53 +215,374 @@ impl<'a> Polynomial<'a> {
         res
     }
 
-    pub fn qdiv(&self, rhs: &Polynomial<'a>) -> (Polynomial, Polynomial) {
+    /// Calculates self(g(x)) mod h(x) with Brent–Kung baby-step giant-step algorithm:
+    /// powers g^0..g^m mod h, where m = ceil(sqrt(len(self))), are precalculated once,
+    /// coefficients of `self` are split into blocks of m and combined with these powers,
+    /// and the blocks are joined by Horner's scheme in g^m. Needs about 2 * sqrt(len(self))
+    /// modular multiplications instead of len(self) for `compose` followed by reduction.
+    pub fn modular_compose(&self, g: &Polynomial<'a>, h: &Polynomial<'a>) -> Polynomial<'a> {
+        Polynomial::check_bin_op_args(self, g);
+        Polynomial::check_bin_op_args(self, h);
+
+        if utils::remove_trailing_elements(&h.coeffs, h.field.zero()).is_empty() {
+            panic!("Impossible to reduce modulo the zero polynomial.");
+        }
+
+        let mul_mod =
+            |lhs: &Polynomial<'a>, rhs: &Polynomial<'a>| (lhs.clone() * rhs.clone()).div_rem(h).1;
+        let block_len = (1..).find(|&m| m * m >= self.coeffs.len()).unwrap();
+
+        let one = Polynomial::new(vec![self.field.one()], self.field, &self.var);
+        let g = g.div_rem(h).1;
+
+        let mut baby_steps = vec![one.div_rem(h).1];
+        while baby_steps.len() <= block_len {
+            let next = mul_mod(baby_steps.last().unwrap(), &g);
+            baby_steps.push(next);
+        }
+        let giant_step = baby_steps.pop().unwrap();
+
+        let mut res = Polynomial::empty(self);
+        for block in self.coeffs.chunks(block_len).rev() {
+            let block_value = block
+                .iter()
+                .zip(baby_steps.iter())
+                .fold(Polynomial::empty(self), |acc, (&coef, pow)| {
+                    acc + pow.scale(coef)
+                });
+            res = mul_mod(&res, &giant_step) + block_value;
+        }
+
+        res
+    }
+
+    /// Multiplies polynomials in the ring F[x]/(x^n - 1). Terms of the product with
+    /// powers not less than `n` are wrapped around instead of dividing by x^n - 1.
+    pub fn mul_mod_cyclic(&self, rhs: &Polynomial<'a>, n: usize) -> Polynomial<'a> {
+        Polynomial::check_bin_op_args(self, rhs);
+
+        if n == 0 {
+            panic!("Impossible to reduce modulo x^0 - 1 cause it is the zero polynomial.");
+        }
+
+        let product_len = (self.coeffs.len() + rhs.coeffs.len()).saturating_sub(1);
+        let mut coeffs = vec![self.field.zero(); cmp::min(n, product_len)];
+
+        for (i, &lhs_coef) in self.coeffs.iter().enumerate() {
+            for (j, &rhs_coef) in rhs.coeffs.iter().enumerate() {
+                coeffs[(i + j) % n] += lhs_coef * rhs_coef;
+            }
+        }
+
+        Polynomial::new(coeffs, self.field, &self.var)
+    }
+
+    /// Same as `div_rem`, kept for compatibility.
+    pub fn qdiv(&self, rhs: &Polynomial<'a>) -> (Polynomial<'a>, Polynomial<'a>) {
         Polynomial::check_bin_op_args(self, rhs);
         self.qdiv_(rhs)
     }
 
+    /// Calculates quotient and remainder of the division of `self` by `rhs`.
+    /// This is the canonical name for `qdiv`.
+    pub fn div_rem(&self, rhs: &Polynomial<'a>) -> (Polynomial<'a>, Polynomial<'a>) {
+        self.qdiv(rhs)
+    }
+
+    /// Same as `div_rem`, but returns `RsError::DivisionByZero`
+    /// instead of panicking when `rhs` is the zero polynomial.
+    pub fn try_div_rem(
+        &self,
+        rhs: &Polynomial<'a>,
+    ) -> Result<(Polynomial<'a>, Polynomial<'a>), RsError> {
+        Polynomial::check_bin_op_args(self, rhs);
+
+        if utils::remove_trailing_elements(&rhs.coeffs, rhs.field.zero()).is_empty() {
+            Err(RsError::DivisionByZero)
+        } else {
+            Ok(self.qdiv_(rhs))
+        }
+    }
+
+    /// Checks whether `self` divides `other` without calculating the quotient.
+    /// The zero polynomial divides only the zero polynomial.
+    pub fn divides(&self, other: &Polynomial<'a>) -> bool {
+        Polynomial::check_bin_op_args(self, other);
+
+        let divisor = utils::remove_trailing_elements(&self.coeffs, self.field.zero());
+        let mut rem = utils::remove_trailing_elements(&other.coeffs, other.field.zero());
+
+        if divisor.is_empty() {
+            return rem.is_empty();
+        }
+
+        Polynomial::reduce_(&mut rem, &divisor, |_, _| {});
+        rem.is_empty()
+    }
+
     pub fn monomial(deg: usize, coef: FieldElement<'a>, field: &'a GaloisField) -> Polynomial<'a> {
-        let mut coeffs = vec![field.zero(); deg];
-        coeffs.push(coef);
-        Polynomial::new(coeffs, field, "x")
+        SparsePolynomial::monomial(deg, coef, field).to_dense()
+    }
+
+    /// Multiplies polynomial by x^n through the sparse form of x^n,
+    /// so no dense vector of zeros is built for the multiplier.
+    pub fn shift(&self, n: usize) -> Polynomial<'a> {
+        &SparsePolynomial::new(vec![(n, self.field.one())], self.field, &self.var) * self
     }
 
+    #[deprecated(note = "use `interpolate_slices` instead")]
+    #[allow(clippy::ptr_arg)]
     pub fn interpolate(
         x: &'a Vec<FieldElement<'a>>,
         y: &'a Vec<FieldElement<'a>>,
     ) -> Polynomial<'a> {
-        if x.is_empty() || y.is_empty() || x.len() != y.len() {
-            panic!("Impossible to establish a one-to-one correspondence between the definition and value areas.");
+        Polynomial::interpolate_slices(x, y)
+    }
+
+    /// Builds polynomial p of the minimal degree such that p(x_i) = y_i for all points.
+    pub fn interpolate_slices(x: &[FieldElement<'a>], y: &[FieldElement<'a>]) -> Polynomial<'a> {
+        match Polynomial::try_interpolate(x, y) {
+            Ok(poly) => poly,
+            Err(err) => panic!("Impossible to interpolate: {}.", err),
+        }
+    }
+
+    /// Same as `interpolate_slices`, but returns an error instead of panicking:
+    /// `RsError::EmptyInterpolationSet` for no points, `RsError::InterpolationLengthMismatch`
+    /// if numbers of points and values differ, `RsError::FieldMismatch` if elements lay
+    /// in different fields and `RsError::DuplicateInterpolationPoint` if definition area
+    /// contains the same point twice.
+    pub fn try_interpolate(
+        x: &[FieldElement<'a>],
+        y: &[FieldElement<'a>],
+    ) -> Result<Polynomial<'a>, RsError> {
+        if x.len() != y.len() {
+            return Err(RsError::InterpolationLengthMismatch {
+                points: x.len(),
+                values: y.len(),
+            });
         }
 
-        let expected_field_ptr = x.first().unwrap().field as *const _;
+        let field = match x.first() {
+            Some(el) => el.field,
+            None => return Err(RsError::EmptyInterpolationSet),
+        };
 
-        for elem in x.iter().chain(y.iter()) {
-            if elem.field as *const _ != expected_field_ptr {
-                panic!("Not all elements are lay in the same field.");
-            }
+        if !x
+            .iter()
+            .chain(y.iter())
+            .all(|el| std::ptr::eq(el.field, field))
+        {
+            return Err(RsError::FieldMismatch);
+        }
+
+        let mut points = HashSet::with_capacity(x.len());
+        if !x.iter().all(|el| points.insert(el.canonical_val())) {
+            return Err(RsError::DuplicateInterpolationPoint);
+        }
+
+        if x.len() == 1 {
+            return Ok(Polynomial::new(vec![y[0]], y[0].field, "x"));
         }
 
         let polynomials = Polynomial::calculate_lagrange_polynomials(x);
-        Polynomial::interpolate_poly_lagrange(y, polynomials)
+        Ok(Polynomial::interpolate_poly_lagrange(y, polynomials))
+    }
+
+    /// Multiplies every coefficient of the polynomial by the scalar `c`.
+    pub fn scale(&self, c: FieldElement<'a>) -> Polynomial<'a> {
+        Polynomial::new(
+            self.coeffs.iter().map(|&coef| coef * c).collect(),
+            self.field,
+            &self.var,
+        )
+    }
+
+    /// Evaluates polynomial at point `x` using Horner's scheme.
+    pub fn evaluate(&self, x: FieldElement<'a>) -> FieldElement<'a> {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(self.field.zero(), |acc, &coef| acc * x + coef)
+    }
+
+    /// Same as `evaluate`, but returns every intermediate state of Horner's scheme, starting
+    /// from the leading coefficient. There is a state per coefficient, the last one is the value
+    /// of the polynomial at `x`, so the trace of the zero polynomial is empty.
+    pub fn evaluate_with_trace(&self, x: FieldElement<'a>) -> Vec<FieldElement<'a>> {
+        self.coeffs
+            .iter()
+            .rev()
+            .scan(self.field.zero(), |acc, &coef| {
+                *acc = *acc * x + coef;
+                Some(*acc)
+            })
+            .collect()
+    }
+
+    /// Formal derivative of the polynomial: sum of i * c_i * x^(i - 1) over all terms c_i * x^i.
+    pub fn derivative(&self) -> Polynomial<'a> {
+        let coeffs = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &coef)| self.field.new_element(i as i128) * coef)
+            .collect();
+
+        Polynomial::new(coeffs, self.field, &self.var)
+    }
+
+    /// Evaluates the formal derivative at every point of `points` with Horner's scheme over
+    /// the coefficients i * c_i, without building the derivative polynomial, e.g. to get
+    /// the error locator derivative at the error positions only.
+    pub fn derivative_at(&self, points: &[FieldElement<'a>]) -> Vec<FieldElement<'a>> {
+        let derivative_coeffs = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &coef)| self.field.new_element(i as i128) * coef)
+            .collect::<Vec<_>>();
+
+        points
+            .iter()
+            .map(|&x| {
+                derivative_coeffs
+                    .iter()
+                    .rev()
+                    .fold(self.field.zero(), |acc, &coef| acc * x + coef)
+            })
+            .collect()
+    }
+
+    /// Divides polynomial by the linear factor (x - c) using Ruffini's rule.
+    /// Returns the quotient and the remainder, that is equal to `self.evaluate(c)`.
+    pub fn synthetic_div(&self, c: FieldElement<'a>) -> (Polynomial<'a>, FieldElement<'a>) {
+        let mut quot = vec![self.field.zero(); self.coeffs.len().saturating_sub(1)];
+        let mut carry = self.field.zero();
+
+        for (i, &coef) in self.coeffs.iter().enumerate().rev() {
+            carry = carry * c + coef;
+            if i > 0 {
+                quot[i - 1] = carry;
+            }
+        }
+
+        (Polynomial::new(quot, self.field, &self.var), carry)
+    }
+
+    /// Divides out the linear factors (x - r) for every root r of `roots` in turn,
+    /// returns `RsError::NotARoot` if some r is not a root of the remaining cofactor.
+    pub fn deflate(&self, roots: &[FieldElement<'a>]) -> Result<Polynomial<'a>, RsError> {
+        roots.iter().try_fold(self.clone(), |poly, &root| {
+            let (quot, rem) = poly.synthetic_div(root);
+            if rem == self.field.zero() {
+                Ok(quot)
+            } else {
+                Err(RsError::NotARoot)
+            }
+        })
+    }
+
+    /// FRI folding step. Splits polynomial as f(x) = f_even(x^2) + x * f_odd(x^2)
+    /// and combines its parts into f_even(x) + alpha * f_odd(x), halving the degree.
+    pub fn fold(&self, alpha: FieldElement<'a>) -> Polynomial<'a> {
+        let coeffs = self
+            .coeffs
+            .chunks(2)
+            .map(|pair| match pair {
+                [even, odd] => *even + alpha * *odd,
+                [even] => *even,
+                _ => unreachable!(),
+            })
+            .collect();
+
+        Polynomial::new(coeffs, self.field, &self.var)
+    }
+
+    /// Reciprocal polynomial x^deg * f(1/x) with the coefficients in reversed order.
+    /// Zero low coefficients become trailing zeros and are removed, so reversing twice
+    /// gives back f divided by the greatest power of x dividing it.
+    pub fn reverse(&self) -> Polynomial<'a> {
+        let coeffs = self.coeffs.iter().rev().copied().collect();
+        Polynomial::new(coeffs, self.field, &self.var)
+    }
+
+    /// Evaluates every polynomial of `polys` at the same point `x`.
+    /// Powers of `x` are calculated once and shared between all polynomials.
+    pub fn evaluate_many_at(
+        polys: &[&Polynomial<'a>],
+        x: FieldElement<'a>,
+    ) -> Vec<FieldElement<'a>> {
+        let max_len = polys
+            .iter()
+            .map(|poly| poly.coeffs.len())
+            .max()
+            .unwrap_or(0);
+        let mut powers = Vec::with_capacity(max_len);
+        let mut cur_pow = x.field.one();
+
+        for _ in 0..max_len {
+            powers.push(cur_pow);
+            cur_pow *= x;
+        }
+
+        polys
+            .iter()
+            .map(|poly| {
+                poly.coeffs
+                    .iter()
+                    .zip(powers.iter())
+                    .fold(x.field.zero(), |acc, (&coef, &pow)| acc + coef * pow)
+            })
+            .collect()
     }
 
-    #[allow(unused)]
-    fn calculate_lagrange_polynomials(x: &'a [FieldElement<'a>]) -> Vec<Polynomial<'a>> {
-        unimplemented!();
+    /// Calculates Lagrange basis polynomials L_i such that
+    /// L_i(x_i) = 1 and L_i(x_j) = 0 for all j != i.
+    fn calculate_lagrange_polynomials(x: &[FieldElement<'a>]) -> Vec<Polynomial<'a>> {
+        let field = x[0].field;
+        let monomials = x
+            .iter()
+            .map(|&x_j| Polynomial::new(vec![-x_j, field.one()], field, "x"))
+            .collect::<Vec<Polynomial<'a>>>();
+
+        x.iter()
+            .enumerate()
+            .map(|(i, &x_i)| {
+                let mut numerator = Polynomial::new(vec![field.one()], field, "x");
+                let mut denominator = field.one();
+
+                for (j, &x_j) in x.iter().enumerate() {
+                    if i != j {
+                        numerator = numerator * monomials[j].clone();
+                        denominator *= x_i - x_j;
+                    }
+                }
+
+                numerator / denominator
+            })
+            .collect()
     }
 
-    #[allow(unused)]
     fn interpolate_poly_lagrange(
-        y: &'a [FieldElement<'a>],
+        y: &[FieldElement<'a>],
         polynomials: Vec<Polynomial<'a>>,
     ) -> Polynomial<'a> {
-        unimplemented!();
+        let field = y[0].field;
+        let mut res = Polynomial::new(vec![], field, "x");
+
+        for (&y_i, poly) in y.iter().zip(polynomials.into_iter()) {
+            res = res + poly.scale(y_i);
+        }
+
+        res
     }
 
     /// Calculates quotient and remainder polynomials such that
     /// f = q * g + r, where deg(r) < deg(g).
-    fn qdiv_(&self, rhs: &Polynomial<'a>) -> (Polynomial, Polynomial) {
+    fn qdiv_(&self, rhs: &Polynomial<'a>) -> (Polynomial<'a>, Polynomial<'a>) {
         let rhs_coeffs = utils::remove_trailing_elements(&rhs.coeffs, rhs.field.zero());
         assert!(!rhs_coeffs.is_empty());
 
@@ -137,32 +591,15 @@ impl<'a> Polynomial<'a> {
             (Polynomial::empty(self), Polynomial::empty(self))
         } else {
             let mut rem = lhs_coeffs;
-            let mut deg_dif = rem.len() as i128 - rhs_coeffs.len() as i128;
+            let deg_dif = rem.len() as i128 - rhs_coeffs.len() as i128;
             let quotient_len = if deg_dif >= -1 {
                 (deg_dif + 1) as usize
             } else {
                 0
             };
             let mut quotient = vec![self.field.zero(); quotient_len];
-            let g_msc_inv = rhs_coeffs.last().unwrap().inverse();
 
-            while deg_dif >= 0 {
-                let tmp = *rem.last().unwrap() * g_msc_inv;
-                let mut last_non_zero = deg_dif - 1;
-
-                quotient[deg_dif as usize] += tmp;
-
-                let offset = deg_dif as usize;
-                for (i, coef) in rhs_coeffs.iter().enumerate() {
-                    rem[i + offset] -= tmp * *coef;
-                    if rem[i + offset] != self.field.zero() {
-                        last_non_zero = (i + offset) as i128;
-                    }
-                }
-
-                rem.truncate((last_non_zero + 1) as usize);
-                deg_dif = rem.len() as i128 - rhs_coeffs.len() as i128;
-            }
+            Polynomial::reduce_(&mut rem, &rhs_coeffs, |pow, term| quotient[pow] += term);
 
             (
                 Polynomial::from(
@@ -174,9 +611,41 @@ impl<'a> Polynomial<'a> {
         }
     }
 
+    /// Reduces `rem` modulo the polynomial with coefficients `rhs_coeffs` in place.
+    /// Every calculated quotient term is passed to `on_quotient_term` along with its power.
+    fn reduce_(
+        rem: &mut Vec<FieldElement<'a>>,
+        rhs_coeffs: &[FieldElement<'a>],
+        mut on_quotient_term: impl FnMut(usize, FieldElement<'a>),
+    ) {
+        let mut deg_dif = rem.len() as i128 - rhs_coeffs.len() as i128;
+        let g_msc_inv = rhs_coeffs.last().unwrap().inverse();
+
+        while deg_dif >= 0 {
+            let tmp = *rem.last().unwrap() * g_msc_inv;
+            let mut last_non_zero = deg_dif - 1;
+
+            on_quotient_term(deg_dif as usize, tmp);
+
+            let offset = deg_dif as usize;
+            for (i, coef) in rhs_coeffs.iter().enumerate() {
+                rem[i + offset] -= tmp * *coef;
+                if rem[i + offset] != tmp.field.zero() {
+                    last_non_zero = (i + offset) as i128;
+                }
+            }
+
+            rem.truncate((last_non_zero + 1) as usize);
+            deg_dif = rem.len() as i128 - rhs_coeffs.len() as i128;
+        }
+    }
+
     fn check_bin_op_args(lhs: &Polynomial, rhs: &Polynomial) {
         if lhs.field as *const _ != rhs.field as *const _ {
-            panic!("Polynomials are biult over different fields!");
+            panic!(
+                "Polynomials are built over different fields: {} and {}!",
+                lhs.field, rhs.field
+            );
         }
 
         if lhs.var != rhs.var {
@@ -184,6 +653,8 @@ impl<'a> Polynomial<'a> {
         }
     }
 
+    /// Applies `op` to the coefficients of the same power. Leading coefficients cancelled
+    /// by `op` are trimmed, so the result has the same form as a polynomial built with `new`.
     fn bin_op(
         lhs: &Polynomial<'a>,
         rhs: &Polynomial<'a>,
@@ -191,14 +662,58 @@ impl<'a> Polynomial<'a> {
     ) -> Polynomial<'a> {
         Polynomial::check_bin_op_args(lhs, rhs);
 
+        let coeffs = utils::zip_longest_with_op(&lhs.coeffs, &rhs.coeffs, op, lhs.field.zero());
+
         Polynomial {
-            coeffs: utils::zip_longest_with_op(&lhs.coeffs, &rhs.coeffs, op, lhs.field.zero()),
+            coeffs: utils::remove_trailing_elements(&coeffs, lhs.field.zero()),
             field: lhs.field,
             var: lhs.var.to_string(),
         }
     }
 }
 
+/// Evaluates the formal derivative of the error `locator` at the given `points`,
+/// as Forney's algorithm needs it only at the error positions.
+pub fn locator_derivative_at<'a>(
+    locator: &Polynomial<'a>,
+    points: &[FieldElement<'a>],
+) -> Vec<FieldElement<'a>> {
+    locator.derivative_at(points)
+}
+
+impl<'a> fmt::Display for Polynomial<'a> {
+    /// Formats polynomial as a sum of its non-zero terms from the highest power,
+    /// e.g. `x^3 + 5x + 2`, the zero polynomial is formatted as `0`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.non_empty() {
+            return write!(f, "0");
+        }
+
+        for (i, (pow, coef)) in self.terms_descending().enumerate() {
+            if i > 0 {
+                write!(f, " + ")?;
+            }
+            if coef.canonical_val() != self.field.one || pow == 0 {
+                write!(f, "{}", coef.canonical_val())?;
+            }
+            match pow {
+                0 => {}
+                1 => write!(f, "{}", self.var)?,
+                _ => write!(f, "{}^{}", self.var, pow)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> AsRef<[FieldElement<'a>]> for Polynomial<'a> {
+    /// Coefficients of the polynomial in ascending order of terms powers.
+    fn as_ref(&self) -> &[FieldElement<'a>] {
+        &self.coeffs
+    }
+}
+
 impl<'a> cmp::PartialEq<Polynomial<'a>> for Polynomial<'a> {
     fn eq(&self, other: &Polynomial<'a>) -> bool {
         self.coeffs == other.coeffs
@@ -221,11 +736,27 @@ impl<'a> ops::Sub<Polynomial<'a>> for Polynomial<'a> {
     }
 }
 
+impl<'a> ops::Sub<&Polynomial<'a>> for &Polynomial<'a> {
+    type Output = Polynomial<'a>;
+
+    fn sub(self, rhs: &Polynomial<'a>) -> Self::Output {
+        Polynomial::bin_op(self, rhs, |a, b| a - b)
+    }
+}
+
 impl<'a> ops::Neg for Polynomial<'a> {
     type Output = Polynomial<'a>;
 
     fn neg(self) -> Self::Output {
-        Polynomial::new(vec![], self.field, &self.var) - self
+        -&self
+    }
+}
+
+impl<'a> ops::Neg for &Polynomial<'a> {
+    type Output = Polynomial<'a>;
+
+    fn neg(self) -> Self::Output {
+        &Polynomial::empty(self) - self
     }
 }
 
@@ -238,15 +769,26 @@ impl<'a> ops::Mul<Polynomial<'a>> for Polynomial<'a> {
         let lhs_raw_coeffs = self
             .coeffs
             .iter()
-            .map(|elem| elem.val)
+            .map(|elem| elem.canonical_val())
+            .collect::<Vec<u64>>();
+        let rhs_raw_coeffs = rhs
+            .coeffs
+            .iter()
+            .map(|elem| elem.canonical_val())
             .collect::<Vec<u64>>();
-        let rhs_raw_coeffs = rhs.coeffs.iter().map(|elem| elem.val).collect::<Vec<u64>>();
-        let res_len = self.deg() + rhs.deg() + 1;
-        let mut res_raw_coeffs = vec![0; cmp::max(res_len, 0) as usize];
+        let res_len = match (self.degree(), rhs.degree()) {
+            (Some(lhs_deg), Some(rhs_deg)) => lhs_deg + rhs_deg + 1,
+            _ => 0,
+        };
+        let mut res_raw_coeffs = vec![0; res_len];
+        let modulus = self.field.k_modulus;
 
         for (i, lhs_val) in lhs_raw_coeffs.into_iter().enumerate() {
             for (j, rhs_val) in rhs_raw_coeffs.iter().enumerate() {
-                res_raw_coeffs[i + j] += lhs_val * rhs_val;
+                let product = self
+                    .field
+                    .barrett_reduce(lhs_val as u128 * *rhs_val as u128);
+                res_raw_coeffs[i + j] = (res_raw_coeffs[i + j] + product) % modulus;
             }
         }
 
@@ -261,6 +803,18 @@ impl<'a> ops::Mul<Polynomial<'a>> for Polynomial<'a> {
     }
 }
 
+impl<'a> ops::Div<FieldElement<'a>> for Polynomial<'a> {
+    type Output = Polynomial<'a>;
+
+    fn div(self, rhs: FieldElement<'a>) -> Self::Output {
+        if rhs.canonical_val() == rhs.field.zero {
+            panic!("Impossible to divide polynomial by the zero element.");
+        }
+
+        self.scale(rhs.inverse())
+    }
+}
+
 impl<'a> ops::Div<&Polynomial<'a>> for &'a Polynomial<'a> {
     type Output = Polynomial<'a>;
 
@@ -275,15 +829,63 @@ impl<'a> ops::Div<&Polynomial<'a>> for &'a Polynomial<'a> {
     }
 }
 
+/// Accumulates coefficients of the polynomial, listed in ascending order of terms powers,
+/// from integers or field elements. Stands in for `FromIterator`, that can't be given a field.
+#[derive(Debug, Clone)]
+pub struct PolynomialBuilder<'a> {
+    coeffs: Vec<FieldElement<'a>>,
+    field: &'a GaloisField,
+    var: String,
+}
+
+impl<'a> PolynomialBuilder<'a> {
+    pub fn new(field: &'a GaloisField, var: &str) -> PolynomialBuilder<'a> {
+        PolynomialBuilder {
+            coeffs: vec![],
+            field,
+            var: var.to_string(),
+        }
+    }
+
+    /// Builds polynomial of the accumulated coefficients, trailing zeros are removed.
+    pub fn finish(self) -> Polynomial<'a> {
+        Polynomial::new(self.coeffs, self.field, &self.var)
+    }
+}
+
+impl<'a> Extend<i128> for PolynomialBuilder<'a> {
+    fn extend<I: IntoIterator<Item = i128>>(&mut self, iter: I) {
+        let field = self.field;
+        self.coeffs
+            .extend(iter.into_iter().map(|num| field.new_element(num)));
+    }
+}
+
+impl<'a> Extend<FieldElement<'a>> for PolynomialBuilder<'a> {
+    fn extend<I: IntoIterator<Item = FieldElement<'a>>>(&mut self, iter: I) {
+        for el in iter {
+            if !std::ptr::eq(el.field, self.field) {
+                panic!(
+                    "Element lays in different field: {} instead of {}.",
+                    el.field, self.field
+                );
+            }
+            self.coeffs.push(el);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Polynomial;
+    use super::{locator_derivative_at, Endianness, Polynomial, PolynomialBuilder};
+    use crate::error::{ParseError, RsError};
     use crate::field::GaloisField;
+    use crate::field_element::FieldElement;
     use crate::utils;
 
     use itertools::izip;
 
-    const FIELD: GaloisField = crate::galois_field!();
+    static FIELD: GaloisField = crate::galois_field!();
 
     #[test]
     fn init_test() {
@@ -391,10 +993,40 @@ mod tests {
 
         for (lhs_raw, rhs_raw, res_raw) in izip!(lhs_data, rhs_data, res_data) {
             let (lhs_poly, rhs_poly, res_poly) = prepare_data_for_bin_op(lhs_raw, rhs_raw, res_raw);
+            assert_eq!(&lhs_poly - &rhs_poly, res_poly);
             assert_eq!(lhs_poly - rhs_poly, res_poly);
         }
     }
 
+    #[test]
+    fn cancelled_leading_term_test() {
+        let lhs_data = [vec![3, 5, 7], vec![3, 5, 7], vec![1, 2]];
+        let rhs_data = [vec![4, 1, -7], vec![3, 5, 7], vec![-1, -2]];
+        let sum_data = [vec![7, 6, 0], vec![6, 10, 14], vec![0, 0]];
+        let diff_data = [vec![-1, 4, 14], vec![0, 0, 0], vec![2, 4]];
+        let res_len_data = [(2, 3), (3, 0), (0, 2)];
+
+        for (lhs_raw, rhs_raw, sum_raw, diff_raw, (sum_len, diff_len)) in
+            izip!(lhs_data, rhs_data, sum_data, diff_data, res_len_data)
+        {
+            let lhs_poly = Polynomial::new(utils::nums_to_elements(lhs_raw, &FIELD), &FIELD, "x");
+            let rhs_poly = Polynomial::new(utils::nums_to_elements(rhs_raw, &FIELD), &FIELD, "x");
+            let sum = lhs_poly.clone() + rhs_poly.clone();
+            let diff = lhs_poly - rhs_poly;
+
+            assert_eq!(sum.coeffs.len(), sum_len);
+            assert_eq!(diff.coeffs.len(), diff_len);
+            assert_eq!(
+                sum,
+                Polynomial::new(utils::nums_to_elements(sum_raw, &FIELD), &FIELD, "x")
+            );
+            assert_eq!(
+                diff,
+                Polynomial::new(utils::nums_to_elements(diff_raw, &FIELD), &FIELD, "x")
+            );
+        }
+    }
+
     #[test]
     fn neg_test() {
         let poly_data = [
@@ -429,6 +1061,8 @@ mod tests {
             let poly = Polynomial::new(poly_coeffs, &FIELD, "x");
             let res = Polynomial::new(res_coeffs, &FIELD, "x");
 
+            assert_eq!(-&poly, -(poly.clone()));
+            assert_eq!(-&poly, res);
             assert_eq!(-poly, res);
         }
     }
@@ -478,6 +1112,75 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mul_denormalized_test() {
+        let vals_data = [
+            vec![FIELD.k_modulus + 3, 5],
+            vec![u64::MAX, 2 * FIELD.k_modulus - 1, 12],
+            vec![7, FIELD.k_modulus],
+        ];
+        let rhs = Polynomial::new(
+            utils::nums_to_elements(vec![11, 2, 1238909831], &FIELD),
+            &FIELD,
+            "x",
+        );
+
+        for vals in vals_data {
+            let mut coeffs = utils::nums_to_elements(vec![0; vals.len()], &FIELD);
+            for (coef, &val) in coeffs.iter_mut().zip(vals.iter()) {
+                coef.val = val;
+            }
+            let denormalized = Polynomial::new(coeffs, &FIELD, "x");
+            let canonical = Polynomial::new(
+                vals.iter()
+                    .map(|&val| FIELD.new_element(val as i128))
+                    .collect(),
+                &FIELD,
+                "x",
+            );
+
+            let (res, expected) = (denormalized * rhs.clone(), canonical * rhs.clone());
+            assert_eq!(
+                res.coeffs.iter().map(|el| el.val).collect::<Vec<_>>(),
+                expected.coeffs.iter().map(|el| el.val).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn new_bounded_test() {
+        let coeffs_data = [vec![], vec![5], vec![1, 2, 3, 0, 0, 0], vec![1, 2, 3, 4]];
+        let max_deg_data = [0, 0, 2, 2];
+        let res_data = [
+            Ok(vec![]),
+            Ok(vec![5]),
+            Ok(vec![1, 2, 3]),
+            Err(RsError::DegreeTooLarge { deg: 3, max_deg: 2 }),
+        ];
+
+        for (coeffs_raw, max_deg, res) in izip!(coeffs_data, max_deg_data, res_data) {
+            let coeffs = utils::nums_to_elements(coeffs_raw, &FIELD);
+            let res =
+                res.map(|raw| Polynomial::new(utils::nums_to_elements(raw, &FIELD), &FIELD, "x"));
+
+            assert_eq!(Polynomial::new_bounded(coeffs, &FIELD, "x", max_deg), res);
+        }
+    }
+
+    #[test]
+    fn compose_empty_test() {
+        let empty = Polynomial::new(vec![], &FIELD, "x");
+        let poly_data = [vec![], vec![7], vec![17, 10, 12, 19, 19], vec![0, 3, 5]];
+        let res_data = [vec![], vec![7], vec![17], vec![]];
+
+        for (poly_raw, res_raw) in izip!(poly_data, res_data) {
+            let (poly, _, res) = prepare_data_for_bin_op(poly_raw, vec![], res_raw);
+
+            assert_eq!(empty.compose(poly.clone()), empty);
+            assert_eq!(poly.compose(empty.clone()), res);
+        }
+    }
+
     #[test]
     fn compose_test() {
         let lhs_data = [
@@ -532,6 +1235,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn modular_compose_test() {
+        let f_data = [
+            vec![],
+            vec![7],
+            vec![17, 10, 12, 19, 19],
+            vec![18, 13, 10, 15, 11, 3, -1, 0, 5, 1039791324],
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17],
+        ];
+        let g_data = [
+            vec![20, 13],
+            vec![11, 18],
+            vec![12, 14, 13, 16, 12],
+            vec![-5, 0, 1],
+            vec![19, 11, 14, 10, 3, 3, 2],
+        ];
+        let h_data = [
+            vec![1, 1],
+            vec![3, 0, 1],
+            vec![15, 16, 14],
+            vec![-1, 0, 0, 0, 0, 0, 0, 1],
+            vec![2, 0, 7, 0, 1],
+        ];
+
+        for (f_raw, g_raw, h_raw) in izip!(f_data, g_data, h_data) {
+            let f = Polynomial::new(utils::nums_to_elements(f_raw, &FIELD), &FIELD, "x");
+            let g = Polynomial::new(utils::nums_to_elements(g_raw, &FIELD), &FIELD, "x");
+            let h = Polynomial::new(utils::nums_to_elements(h_raw, &FIELD), &FIELD, "x");
+
+            assert_eq!(
+                f.modular_compose(&g, &h),
+                f.compose(g.clone()).div_rem(&h).1
+            );
+        }
+
+        let f = Polynomial::new(utils::nums_to_elements(vec![1, 2, 3], &FIELD), &FIELD, "x");
+        let constant = Polynomial::new(vec![FIELD.new_element(5)], &FIELD, "x");
+        assert_eq!(f.modular_compose(&f, &constant), Polynomial::empty(&f));
+    }
+
+    #[test]
+    #[should_panic(expected = "Impossible to reduce modulo the zero polynomial.")]
+    fn modular_compose_zero_modulus_test() {
+        let f = Polynomial::new(utils::nums_to_elements(vec![1, 2, 3], &FIELD), &FIELD, "x");
+        f.modular_compose(&f, &Polynomial::empty(&f));
+    }
+
     #[test]
     fn qdiv_test() {
         let lhs_data = [
@@ -590,6 +1340,187 @@ mod tests {
         }
     }
 
+    #[test]
+    fn div_rem_test() {
+        let lhs_data = [
+            vec![176, 157, 149, 103],
+            vec![117, 110, 127],
+            vec![119, 138, 195, 108, 127, 132],
+            vec![],
+        ];
+        let rhs_data = [
+            vec![123, 184, 191, 100],
+            vec![136, 124, 147],
+            vec![185, 139, 116],
+            vec![3, 1],
+        ];
+
+        for (lhs_raw, rhs_raw) in izip!(lhs_data, rhs_data) {
+            let (lhs_poly, rhs_poly, _) = prepare_data_for_bin_op(lhs_raw, rhs_raw, vec![]);
+            let (quot, rem) = lhs_poly.div_rem(&rhs_poly);
+
+            assert_eq!((quot.clone(), rem.clone()), lhs_poly.qdiv(&rhs_poly));
+            assert_eq!(quot * rhs_poly + rem, lhs_poly);
+        }
+    }
+
+    #[test]
+    fn try_div_rem_test() {
+        let (lhs_poly, rhs_poly, _) = prepare_data_for_bin_op(
+            vec![119, 138, 195, 108, 127, 132],
+            vec![185, 139, 116],
+            vec![],
+        );
+        let zero_poly = Polynomial::new(vec![], &FIELD, "x");
+        let untrimmed_zero_poly = Polynomial {
+            coeffs: utils::nums_to_elements(vec![0, 0, 0], &FIELD),
+            field: &FIELD,
+            var: "x".to_string(),
+        };
+
+        assert_eq!(
+            lhs_poly.try_div_rem(&rhs_poly),
+            Ok(lhs_poly.div_rem(&rhs_poly))
+        );
+        assert_eq!(
+            lhs_poly.try_div_rem(&zero_poly),
+            Err(RsError::DivisionByZero)
+        );
+        assert_eq!(
+            lhs_poly.try_div_rem(&untrimmed_zero_poly),
+            Err(RsError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn mul_mod_cyclic_test() {
+        let lhs_data = [
+            vec![176, 157, 149, 103],
+            vec![1, 2, 3],
+            vec![0, 0, 0, 0, 0, 0, 1],
+            vec![],
+            vec![-2437383495i64, 171, 195, 160, 157, 2373853268, -1712893553],
+        ];
+        let rhs_data = [
+            vec![123, 184, 191, 100],
+            vec![4, 5],
+            vec![0, 1],
+            vec![3, 4],
+            vec![121, 0, 0, 1039791324],
+        ];
+        let n_data = [3, 8, 7, 2, 4];
+
+        for (lhs_raw, rhs_raw, n) in izip!(lhs_data, rhs_data, n_data) {
+            let lhs = Polynomial::new(utils::nums_to_elements(lhs_raw, &FIELD), &FIELD, "x");
+            let rhs = Polynomial::new(utils::nums_to_elements(rhs_raw, &FIELD), &FIELD, "x");
+            let cyclic_modulus = Polynomial::monomial(n, FIELD.one(), &FIELD)
+                - Polynomial::new(vec![FIELD.one()], &FIELD, "x");
+            let (_, res) = (lhs.clone() * rhs.clone()).qdiv(&cyclic_modulus);
+
+            assert_eq!(lhs.mul_mod_cyclic(&rhs, n), res);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Impossible to reduce modulo x^0 - 1")]
+    fn mul_mod_cyclic_zero_modulus_test() {
+        let poly = Polynomial::x(&FIELD);
+        poly.mul_mod_cyclic(&poly, 0);
+    }
+
+    #[test]
+    fn synthetic_div_test() {
+        let poly_data = [
+            vec![176, 157, 149, 103],
+            vec![-6, 11, -6, 1],
+            vec![12],
+            vec![],
+            vec![2373853268i64, 0, 0, 0, 0, -1712893553],
+        ];
+        let c_data = [5, 2, 7, 3, -1039791324];
+
+        for (poly_raw, c) in izip!(poly_data, c_data) {
+            let poly = Polynomial::new(utils::nums_to_elements(poly_raw, &FIELD), &FIELD, "x");
+            let c = FIELD.new_element(c);
+            let linear = Polynomial::new(vec![-c, FIELD.one()], &FIELD, "x");
+            let (quot, rem) = poly.qdiv(&linear);
+            let (synthetic_quot, synthetic_rem) = poly.synthetic_div(c);
+
+            assert_eq!(synthetic_quot, quot);
+            assert_eq!(synthetic_rem, poly.evaluate(c));
+            assert_eq!(Polynomial::new(vec![synthetic_rem], &FIELD, "x"), rem);
+        }
+    }
+
+    #[test]
+    fn deflate_test() {
+        let factor = FIELD.new_element(7);
+        let roots = utils::nums_to_elements(vec![1, -2, 3, 3, 1039791324], &FIELD);
+        let poly = roots
+            .iter()
+            .fold(Polynomial::new(vec![factor], &FIELD, "x"), |acc, &root| {
+                acc * Polynomial::new(vec![-root, FIELD.one()], &FIELD, "x")
+            });
+
+        assert_eq!(
+            poly.deflate(&roots),
+            Ok(Polynomial::new(vec![factor], &FIELD, "x"))
+        );
+        assert_eq!(poly.deflate(&roots[..2]).unwrap().deg(), poly.deg() - 2);
+        assert_eq!(poly.deflate(&[]), Ok(poly.clone()));
+        assert_eq!(
+            poly.deflate(&utils::nums_to_elements(vec![3, 3, 3], &FIELD)),
+            Err(RsError::NotARoot)
+        );
+        assert_eq!(
+            poly.deflate(&utils::nums_to_elements(vec![2], &FIELD)),
+            Err(RsError::NotARoot)
+        );
+    }
+
+    #[test]
+    fn divides_test() {
+        let divisor_data = [
+            vec![1, 1],
+            vec![-2, 0, 1],
+            vec![123, 184, 191, 100],
+            vec![5],
+            vec![],
+        ];
+        let other_data = [
+            vec![14, 12, 13, 13],
+            vec![129, 127, 140, 133, 194, 100, 169],
+            vec![176, 157, 149, 103],
+            vec![119, 138, 195],
+            vec![17, 15],
+        ];
+        let res_data = [false, false, false, true, false];
+
+        for (divisor_raw, other_raw, res) in izip!(divisor_data, other_data, res_data) {
+            let (divisor, other, _) = prepare_data_for_bin_op(divisor_raw, other_raw, vec![]);
+            let product = divisor.clone() * other.clone();
+
+            assert!(divisor.divides(&product));
+            assert_eq!(divisor.divides(&other), res);
+        }
+    }
+
+    #[test]
+    fn shift_test() {
+        let poly_data = [vec![], vec![12], vec![16, 10, 13], vec![0, 3, 0, 7]];
+        let n_data = [0, 1, 5, 1000];
+
+        for poly_raw in poly_data {
+            let poly = Polynomial::new(utils::nums_to_elements(poly_raw, &FIELD), &FIELD, "x");
+            for n in n_data {
+                assert_eq!(
+                    poly.shift(n),
+                    poly.clone() * Polynomial::monomial(n, FIELD.one(), &FIELD)
+                );
+            }
+        }
+    }
+
     #[test]
     fn monomial_test() {
         assert_eq!(
@@ -607,6 +1538,651 @@ mod tests {
         );
     }
 
+    #[test]
+    fn degree_test() {
+        let poly_data = [vec![], vec![0], vec![7], vec![7, 0, 0], vec![1, 2, 0, 4]];
+        let res_data = [None, None, Some(0), Some(0), Some(3)];
+
+        for (poly_raw, res) in izip!(poly_data, res_data) {
+            let poly = Polynomial::new(utils::nums_to_elements(poly_raw, &FIELD), &FIELD, "x");
+
+            assert_eq!(poly.degree(), res);
+            assert_eq!(poly.deg(), res.map_or(-1, |deg| deg as i64));
+        }
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(
+            Polynomial::parse("1, 0x2, -3,0", &FIELD, "x"),
+            Ok(Polynomial::new(
+                utils::nums_to_elements(vec![1, 2, -3], &FIELD),
+                &FIELD,
+                "x"
+            ))
+        );
+        assert_eq!(
+            Polynomial::parse(" ", &FIELD, "x"),
+            Ok(Polynomial::new(vec![], &FIELD, "x"))
+        );
+        assert_eq!(
+            Polynomial::parse("1,,2", &FIELD, "x"),
+            Err(ParseError::InvalidNumber("".to_string()))
+        );
+        assert_eq!(
+            Polynomial::parse("1, two", &FIELD, "x"),
+            Err(ParseError::InvalidNumber(" two".to_string()))
+        );
+    }
+
+    #[test]
+    fn terms_test() {
+        let poly = Polynomial::new(
+            utils::nums_to_elements(vec![0, 7, 0, 0, -2, 0, 1], &FIELD),
+            &FIELD,
+            "x",
+        );
+
+        assert_eq!(
+            poly.terms().collect::<Vec<_>>(),
+            vec![
+                (1, FIELD.new_element(7)),
+                (4, FIELD.new_element(-2)),
+                (6, FIELD.one())
+            ]
+        );
+        assert_eq!(Polynomial::new(vec![], &FIELD, "x").terms().count(), 0);
+    }
+
+    #[test]
+    fn terms_descending_test() {
+        let poly = Polynomial::new(
+            utils::nums_to_elements(vec![0, 7, 0, 0, -2, 0, 1], &FIELD),
+            &FIELD,
+            "x",
+        );
+        let mut reversed = poly.terms().collect::<Vec<_>>();
+        reversed.reverse();
+
+        assert_eq!(
+            poly.terms_descending().collect::<Vec<_>>(),
+            vec![
+                (6, FIELD.one()),
+                (4, FIELD.new_element(-2)),
+                (1, FIELD.new_element(7))
+            ]
+        );
+        assert_eq!(poly.terms_descending().collect::<Vec<_>>(), reversed);
+        assert_eq!(
+            Polynomial::new(vec![], &FIELD, "x")
+                .terms_descending()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn cmp_canonical_test() {
+        let poly_data = [
+            vec![1, 0, 2],
+            vec![],
+            vec![5, 1],
+            vec![0, 0, 1],
+            vec![3],
+            vec![7, 1],
+            vec![0],
+            vec![-1, 0, 2],
+            vec![0, 1],
+        ];
+        let sorted_data = [
+            vec![],
+            vec![],
+            vec![3],
+            vec![0, 1],
+            vec![5, 1],
+            vec![7, 1],
+            vec![0, 0, 1],
+            vec![1, 0, 2],
+            vec![-1, 0, 2],
+        ];
+
+        let mut polys = poly_data
+            .into_iter()
+            .map(|raw| Polynomial::new(utils::nums_to_elements(raw, &FIELD), &FIELD, "x"))
+            .collect::<Vec<_>>();
+        polys.sort_by(|lhs, rhs| lhs.cmp_canonical(rhs));
+
+        for (poly, sorted_raw) in izip!(polys, sorted_data) {
+            assert_eq!(
+                poly,
+                Polynomial::new(utils::nums_to_elements(sorted_raw, &FIELD), &FIELD, "x")
+            );
+        }
+    }
+
+    #[test]
+    fn builder_test() {
+        static SMALL_FIELD: GaloisField = GaloisField::new(17, 3);
+        let expected = Polynomial::new(
+            utils::nums_to_elements(vec![5, -1, 0, 3221225472i64, 13], &FIELD),
+            &FIELD,
+            "x",
+        );
+
+        let mut builder = PolynomialBuilder::new(&FIELD, "x");
+        builder.extend([5, -1]);
+        builder.extend(vec![0, -1, 13, 0, 3221225473]);
+        assert_eq!(builder.finish(), expected);
+
+        let mut builder = PolynomialBuilder::new(&FIELD, "x");
+        builder.extend(utils::nums_to_elements(vec![5, -1, 0], &FIELD));
+        builder.extend(expected.as_ref()[3..].iter().copied());
+        assert_eq!(builder.finish(), expected);
+
+        let mut builder = PolynomialBuilder::new(&SMALL_FIELD, "y");
+        builder.extend((0..20).map(|num: i128| num * num));
+        assert_eq!(
+            builder.finish(),
+            Polynomial::new(
+                utils::nums_to_elements((0..20).map(|num: i128| num * num), &SMALL_FIELD),
+                &SMALL_FIELD,
+                "y"
+            )
+        );
+        assert_eq!(
+            PolynomialBuilder::new(&FIELD, "x").finish(),
+            Polynomial::new(vec![], &FIELD, "x")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Element lays in different field")]
+    fn builder_different_field_test() {
+        static SMALL_FIELD: GaloisField = GaloisField::new(17, 3);
+
+        let mut builder = PolynomialBuilder::new(&FIELD, "x");
+        builder.extend([SMALL_FIELD.one()]);
+    }
+
+    #[test]
+    fn display_test() {
+        let poly_data = [
+            vec![],
+            vec![5],
+            vec![1],
+            vec![0, 7, 0, 0, -2, 0, 1],
+            vec![3, 1],
+        ];
+        let res_data = ["0", "5", "1", "x^6 + 3221225471x^4 + 7x", "x + 3"];
+
+        for (poly_raw, res) in izip!(poly_data, res_data) {
+            let poly = Polynomial::new(utils::nums_to_elements(poly_raw, &FIELD), &FIELD, "x");
+            assert_eq!(poly.to_string(), res);
+        }
+    }
+
+    #[test]
+    fn to_debug_json_test() {
+        let poly = Polynomial::new(utils::nums_to_elements(vec![3, 0, -1], &FIELD), &FIELD, "x");
+
+        assert_eq!(
+            poly.to_debug_json(),
+            r#"{"var": "x", "coeffs": [3, 0, 3221225472], "deg": 2}"#
+        );
+        assert_eq!(
+            Polynomial::new(vec![], &FIELD, "t\"").to_debug_json(),
+            r#"{"var": "t\"", "coeffs": [], "deg": -1}"#
+        );
+    }
+
+    #[test]
+    fn try_from_bytes_test() {
+        static SMALL_FIELD: GaloisField = GaloisField::new(251, 6);
+
+        let bytes = [1, 0, 0, 0, 0xff, 0xff, 0xff, 0xbf, 0x01, 0x02, 0x03, 0x04];
+        assert_eq!(
+            Polynomial::try_from_bytes(&bytes, Endianness::Little, &FIELD, "x"),
+            Ok(Polynomial::new(
+                utils::nums_to_elements(vec![1, 0xbfffffffi64, 0x04030201], &FIELD),
+                &FIELD,
+                "x"
+            ))
+        );
+        assert_eq!(
+            Polynomial::try_from_bytes(&[], Endianness::Little, &FIELD, "x"),
+            Ok(Polynomial::new(vec![], &FIELD, "x"))
+        );
+        assert_eq!(
+            Polynomial::try_from_bytes(&[7, 0, 250, 0], Endianness::Little, &SMALL_FIELD, "x"),
+            Ok(Polynomial::new(
+                utils::nums_to_elements(vec![7, 0, 250], &SMALL_FIELD),
+                &SMALL_FIELD,
+                "x"
+            ))
+        );
+    }
+
+    #[test]
+    fn to_bytes_test() {
+        static SMALL_FIELD: GaloisField = GaloisField::new(251, 6);
+
+        let poly = Polynomial::new(
+            utils::nums_to_elements(vec![1, 0xbfffffffi64, 0x04030201], &FIELD),
+            &FIELD,
+            "x",
+        );
+        let little = poly.to_bytes(Endianness::Little);
+        let big = poly.to_bytes(Endianness::Big);
+
+        assert_eq!(
+            little,
+            vec![1, 0, 0, 0, 0xff, 0xff, 0xff, 0xbf, 0x01, 0x02, 0x03, 0x04]
+        );
+        assert_eq!(
+            big,
+            vec![0, 0, 0, 1, 0xbf, 0xff, 0xff, 0xff, 0x04, 0x03, 0x02, 0x01]
+        );
+
+        for (bytes, endianness) in [(little, Endianness::Little), (big, Endianness::Big)] {
+            assert_eq!(
+                Polynomial::try_from_bytes(&bytes, endianness, &FIELD, "x"),
+                Ok(poly.clone())
+            );
+        }
+
+        let small_poly = Polynomial::new(
+            utils::nums_to_elements(vec![7, 0, 250], &SMALL_FIELD),
+            &SMALL_FIELD,
+            "x",
+        );
+        assert_eq!(small_poly.to_bytes(Endianness::Little), vec![7, 0, 250]);
+        assert_eq!(small_poly.to_bytes(Endianness::Big), vec![7, 0, 250]);
+        assert!(Polynomial::new(vec![], &FIELD, "x")
+            .to_bytes(Endianness::Big)
+            .is_empty());
+    }
+
+    #[test]
+    fn try_from_bytes_misaligned_test() {
+        assert_eq!(
+            Polynomial::try_from_bytes(&[1, 2, 3, 4, 5, 6], Endianness::Little, &FIELD, "x"),
+            Err(ParseError::MisalignedLength {
+                len: 6,
+                symbol_width: 4
+            })
+        );
+        assert_eq!(
+            Polynomial::try_from_bytes(&[1, 2, 3], Endianness::Little, &FIELD, "x"),
+            Err(ParseError::MisalignedLength {
+                len: 3,
+                symbol_width: 4
+            })
+        );
+    }
+
+    #[test]
+    fn as_ref_test() {
+        fn sum_of(coeffs: &[FieldElement]) -> u64 {
+            coeffs.iter().map(|coef| coef.val).sum()
+        }
+
+        let poly = Polynomial::new(
+            utils::nums_to_elements(vec![3, 0, 2, 1, 0, 0], &FIELD),
+            &FIELD,
+            "x",
+        );
+
+        assert_eq!(
+            poly.as_ref(),
+            &utils::nums_to_elements(vec![3, 0, 2, 1], &FIELD)[..]
+        );
+        assert_eq!(sum_of(poly.as_ref()), 6);
+        assert!(Polynomial::new(vec![], &FIELD, "x").as_ref().is_empty());
+    }
+
+    #[test]
+    fn scale_test() {
+        let poly_data = [
+            vec![],
+            vec![5],
+            vec![176, 0, 149, 103],
+            vec![-1, 2160990732i64],
+        ];
+        let c_data = [3, -1, 1039791324, 2];
+
+        for (poly_raw, c) in izip!(poly_data, c_data) {
+            let poly = Polynomial::new(utils::nums_to_elements(poly_raw, &FIELD), &FIELD, "x");
+            let c = FIELD.new_element(c);
+
+            assert_eq!(
+                poly.scale(c),
+                poly.clone() * Polynomial::new(vec![c], &FIELD, "x")
+            );
+            assert_eq!(poly.scale(c) / c, poly);
+        }
+
+        let poly = Polynomial::x(&FIELD);
+        assert_eq!(
+            poly.scale(FIELD.zero()),
+            Polynomial::new(vec![], &FIELD, "x")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Impossible to divide polynomial by the zero element.")]
+    fn div_by_zero_element_test() {
+        let _ = Polynomial::x(&FIELD) / FIELD.zero();
+    }
+
+    #[test]
+    fn evaluate_test() {
+        let poly = Polynomial::new(
+            utils::nums_to_elements(vec![3, 0, 2, 1], &FIELD),
+            &FIELD,
+            "x",
+        );
+
+        assert_eq!(poly.evaluate(FIELD.new_element(0)), FIELD.new_element(3));
+        assert_eq!(poly.evaluate(FIELD.new_element(2)), FIELD.new_element(19));
+        assert_eq!(poly.evaluate(FIELD.new_element(-3)), FIELD.new_element(-6));
+        assert_eq!(
+            Polynomial::new(vec![], &FIELD, "x").evaluate(FIELD.new_element(5)),
+            FIELD.zero()
+        );
+    }
+
+    #[test]
+    fn evaluate_with_trace_test() {
+        let poly = Polynomial::new(utils::nums_to_elements(vec![5, 8, 13], &FIELD), &FIELD, "x");
+        assert_eq!(
+            poly.evaluate_with_trace(FIELD.new_element(2)),
+            utils::nums_to_elements(vec![13, 34, 73], &FIELD)
+        );
+
+        let poly_data = [
+            vec![],
+            vec![-2437383495i64],
+            vec![121, 171, 195, 160, 157, 2373853268, -1712893553],
+            vec![0, 0, 0, 1],
+        ];
+
+        for raw in poly_data {
+            let poly = Polynomial::new(utils::nums_to_elements(raw, &FIELD), &FIELD, "x");
+
+            for point in [0, 1, 17, -1056252449] {
+                let point = FIELD.new_element(point);
+                let trace = poly.evaluate_with_trace(point);
+
+                assert_eq!(trace.len(), poly.as_ref().len());
+                assert_eq!(
+                    trace.last().copied().unwrap_or(FIELD.zero()),
+                    poly.evaluate(point)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn derivative_test() {
+        let poly_data = [
+            vec![],
+            vec![42],
+            vec![5, 8, 13],
+            vec![121, 171, 195, 160, 157, 2373853268i64, -1712893553],
+        ];
+        let res_data = [
+            vec![],
+            vec![],
+            vec![8, 26],
+            vec![171, 390, 480, 628, 11869266340i64, -10277361318],
+        ];
+        let points = utils::nums_to_elements(vec![0, 1, 17, -1056252449], &FIELD);
+
+        for (poly_raw, res_raw) in izip!(poly_data, res_data) {
+            let poly = Polynomial::new(utils::nums_to_elements(poly_raw, &FIELD), &FIELD, "x");
+            let derivative = poly.derivative();
+            let expected = points
+                .iter()
+                .map(|&point| derivative.evaluate(point))
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                derivative,
+                Polynomial::new(utils::nums_to_elements(res_raw, &FIELD), &FIELD, "x")
+            );
+            assert_eq!(poly.derivative_at(&points), expected);
+            assert_eq!(locator_derivative_at(&poly, &points), expected);
+            assert!(poly.derivative_at(&[]).is_empty());
+        }
+    }
+
+    #[test]
+    fn evaluate_many_at_test() {
+        let poly_data = [
+            vec![180, 144, 113, 100, 172],
+            vec![],
+            vec![-2437383495i64],
+            vec![121, 171, 195, 160, 157, 2373853268, -1712893553],
+            vec![138, 181],
+        ];
+        let polys = poly_data
+            .into_iter()
+            .map(|raw| Polynomial::new(utils::nums_to_elements(raw, &FIELD), &FIELD, "x"))
+            .collect::<Vec<Polynomial>>();
+        let poly_refs = polys.iter().collect::<Vec<&Polynomial>>();
+
+        for point in [0, 1, 17, -1056252449] {
+            let point = FIELD.new_element(point);
+            let expected = polys
+                .iter()
+                .map(|poly| poly.evaluate(point))
+                .collect::<Vec<_>>();
+
+            assert_eq!(Polynomial::evaluate_many_at(&poly_refs, point), expected);
+        }
+        assert!(Polynomial::evaluate_many_at(&[], FIELD.one()).is_empty());
+    }
+
+    #[test]
+    fn reverse_test() {
+        let poly_data = [
+            vec![],
+            vec![42],
+            vec![5, 8, 13],
+            vec![0, 0, 7, 0, 1],
+            vec![-1, 0, 0, 2373853268i64],
+        ];
+        let res_data = [
+            vec![],
+            vec![42],
+            vec![13, 8, 5],
+            vec![1, 0, 7],
+            vec![2373853268i64, 0, 0, -1],
+        ];
+        let twice_data = [
+            vec![],
+            vec![42],
+            vec![5, 8, 13],
+            vec![7, 0, 1],
+            vec![-1, 0, 0, 2373853268i64],
+        ];
+
+        for (poly_raw, res_raw, twice_raw) in izip!(poly_data, res_data, twice_data) {
+            let poly = Polynomial::new(utils::nums_to_elements(poly_raw, &FIELD), &FIELD, "x");
+            let res = Polynomial::new(utils::nums_to_elements(res_raw, &FIELD), &FIELD, "x");
+            let twice = Polynomial::new(utils::nums_to_elements(twice_raw, &FIELD), &FIELD, "x");
+
+            assert_eq!(poly.reverse(), res);
+            assert_eq!(poly.reverse().reverse(), twice);
+        }
+    }
+
+    #[test]
+    fn fold_test() {
+        let poly_data = [
+            vec![17, 10, 12, 19, 19],
+            vec![20, 13],
+            vec![
+                -2437383495,
+                2373853268,
+                -1712893553,
+                32691010,
+                903348446,
+                -982274076,
+            ],
+            vec![5],
+            vec![],
+        ];
+
+        for poly_raw in poly_data {
+            let even_raw = poly_raw.iter().step_by(2).copied().collect::<Vec<_>>();
+            let odd_raw = poly_raw
+                .iter()
+                .skip(1)
+                .step_by(2)
+                .copied()
+                .collect::<Vec<_>>();
+            let (poly, even, odd) = prepare_data_for_bin_op(poly_raw, even_raw, odd_raw);
+            let x = Polynomial::x(&FIELD);
+            let x_squared = x.clone() * x.clone();
+
+            assert_eq!(
+                even.compose(x_squared.clone()) + x * odd.compose(x_squared),
+                poly
+            );
+
+            for alpha in [0, 1, 7, -1056252449] {
+                let alpha = FIELD.new_element(alpha);
+                let alpha_poly = Polynomial::new(vec![alpha], &FIELD, "x");
+
+                assert_eq!(poly.fold(alpha), even.clone() + alpha_poly * odd.clone());
+            }
+        }
+    }
+
+    #[test]
+    fn interpolate_test() {
+        let x = utils::nums_to_elements(vec![1, 2, 3, 5, 8], &FIELD);
+        let y = utils::nums_to_elements(vec![-408821234, 2160990732i64, 7, 0, 459438645], &FIELD);
+        let poly = Polynomial::interpolate_slices(&x, &y);
+
+        assert_eq!(poly.deg(), 4);
+        for (&x_i, &y_i) in x.iter().zip(y.iter()) {
+            assert_eq!(poly.evaluate(x_i), y_i);
+        }
+    }
+
+    #[test]
+    fn interpolate_slices_test() {
+        let x = [FIELD.new_element(1), FIELD.new_element(3)];
+        let y = [FIELD.new_element(5), FIELD.new_element(11)];
+        let res = Polynomial::new(utils::nums_to_elements(vec![2, 3], &FIELD), &FIELD, "x");
+
+        assert_eq!(Polynomial::interpolate_slices(&x, &y), res);
+        assert_eq!(
+            Polynomial::interpolate_slices(&x[..1], &y[..1]),
+            Polynomial::new(vec![y[0]], &FIELD, "x")
+        );
+
+        let (x_vec, y_vec) = (x.to_vec(), y.to_vec());
+        #[allow(deprecated)]
+        let deprecated_res = Polynomial::interpolate(&x_vec, &y_vec);
+        assert_eq!(deprecated_res, res);
+    }
+
+    #[test]
+    fn interpolate_one_point_test() {
+        let x = utils::nums_to_elements(vec![7], &FIELD);
+        let y = utils::nums_to_elements(vec![-1056252449], &FIELD);
+
+        assert_eq!(
+            Polynomial::interpolate_slices(&x, &y),
+            Polynomial::new(y.clone(), &FIELD, "x")
+        );
+    }
+
+    #[test]
+    fn interpolate_two_points_test() {
+        let x = utils::nums_to_elements(vec![1, 3], &FIELD);
+        let y = utils::nums_to_elements(vec![5, 11], &FIELD);
+
+        assert_eq!(
+            Polynomial::interpolate_slices(&x, &y),
+            Polynomial::new(utils::nums_to_elements(vec![2, 3], &FIELD), &FIELD, "x")
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Impossible to interpolate: 3 interpolation points don't match 2 values."
+    )]
+    fn interpolate_mismatched_lengths_test() {
+        let x = utils::nums_to_elements(vec![1, 2, 3], &FIELD);
+        let y = utils::nums_to_elements(vec![4, 5], &FIELD);
+
+        Polynomial::interpolate_slices(&x, &y);
+    }
+
+    #[test]
+    fn try_interpolate_test() {
+        let x = utils::nums_to_elements(vec![1, 2, 3], &FIELD);
+        let y = utils::nums_to_elements(vec![4, 5, 7], &FIELD);
+        let duplicate_x = utils::nums_to_elements(vec![1, 2, 3221225474i64], &FIELD);
+
+        assert_eq!(
+            Polynomial::try_interpolate(&x, &y),
+            Ok(Polynomial::interpolate_slices(&x, &y))
+        );
+        assert_eq!(
+            Polynomial::try_interpolate(&duplicate_x, &y),
+            Err(RsError::DuplicateInterpolationPoint)
+        );
+    }
+
+    #[test]
+    fn try_interpolate_invalid_args_test() {
+        static SMALL_FIELD: GaloisField = GaloisField::new(17, 3);
+
+        let x = utils::nums_to_elements(vec![1, 2, 3], &FIELD);
+        let y = utils::nums_to_elements(vec![4, 5], &FIELD);
+        let mut mixed_y = utils::nums_to_elements(vec![4, 5], &FIELD);
+        mixed_y.push(SMALL_FIELD.one());
+
+        assert_eq!(
+            Polynomial::try_interpolate(&[], &[]),
+            Err(RsError::EmptyInterpolationSet)
+        );
+        assert_eq!(
+            Polynomial::try_interpolate(&x, &y),
+            Err(RsError::InterpolationLengthMismatch {
+                points: 3,
+                values: 2
+            })
+        );
+        assert_eq!(
+            Polynomial::try_interpolate(&x, &mixed_y),
+            Err(RsError::FieldMismatch)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Impossible to interpolate: interpolation points are not distinct.")]
+    fn interpolate_duplicate_points_test() {
+        let x = utils::nums_to_elements(vec![5, 8, 5], &FIELD);
+        let y = utils::nums_to_elements(vec![1, 2, 3], &FIELD);
+
+        Polynomial::interpolate_slices(&x, &y);
+    }
+
+    #[test]
+    #[should_panic(expected = "Impossible to interpolate: interpolation set of points is empty.")]
+    fn interpolate_empty_test() {
+        let x = vec![];
+        let y = vec![];
+
+        Polynomial::interpolate_slices(&x, &y);
+    }
+
     fn prepare_data_for_bin_op<'a>(
         lhs_raw: Vec<i128>,
         rhs_raw: Vec<i128>,
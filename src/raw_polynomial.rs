@@ -0,0 +1,151 @@
+use crate::error::ParseError;
+use crate::field::GaloisField;
+use crate::field_element::FieldElement;
+use crate::polynomial::{Endianness, Polynomial};
+
+use std::cmp;
+
+/// Polynomial above some finite field `field` that keeps all of its coefficients,
+/// including the trailing zeros, so fixed-length codewords don't lose their length.
+/// Use `to_polynomial` to get the trimmed form for arithmetic.
+/// # Arguments
+/// * `coeffs` - the coefficients of the polynomial, listed in ascending order of terms powers
+/// * `field` - some finite field that provides polynomial coefficients
+/// * `var` - polynomial variable designation
+#[derive(Debug, Clone)]
+pub struct RawPolynomial<'a> {
+    coeffs: Vec<FieldElement<'a>>,
+    field: &'a GaloisField,
+    var: String,
+}
+
+impl<'a> RawPolynomial<'a> {
+    pub fn new(
+        coeffs: Vec<FieldElement<'a>>,
+        field: &'a GaloisField,
+        var: &str,
+    ) -> RawPolynomial<'a> {
+        for el in coeffs.iter() {
+            if !std::ptr::eq(el.field, field) {
+                panic!(
+                    "Not all coefficients of the constructing
+                polynomial are lay in the same field!"
+                );
+            }
+        }
+
+        RawPolynomial {
+            coeffs,
+            field,
+            var: var.to_string(),
+        }
+    }
+
+    /// Pads coefficients of `poly` with zeros up to `len` coefficients.
+    pub fn with_len(poly: &Polynomial<'a>, len: usize) -> RawPolynomial<'a> {
+        let mut coeffs = poly.as_ref().to_vec();
+        if coeffs.len() > len {
+            panic!(
+                "Impossible to fit polynomial of degree {} into {} coefficients.",
+                poly.deg(),
+                len
+            );
+        }
+        coeffs.resize(len, poly.field().zero());
+
+        RawPolynomial::new(coeffs, poly.field(), poly.var())
+    }
+
+    /// Reads `bytes` in the format of `Polynomial::try_from_bytes`,
+    /// keeping a coefficient for every symbol.
+    pub fn try_from_bytes(
+        bytes: &[u8],
+        endianness: Endianness,
+        field: &'a GaloisField,
+        var: &str,
+    ) -> Result<RawPolynomial<'a>, ParseError> {
+        let poly = Polynomial::try_from_bytes(bytes, endianness, field, var)?;
+        Ok(RawPolynomial::with_len(
+            &poly,
+            bytes.len() / field.byte_width(),
+        ))
+    }
+
+    /// Serializes all coefficients, including the trailing zeros, in the format
+    /// read by `try_from_bytes`.
+    pub fn to_bytes(&self, endianness: Endianness) -> Vec<u8> {
+        let mut bytes = self.to_polynomial().to_bytes(endianness);
+        bytes.resize(self.len() * self.field.byte_width(), 0);
+        bytes
+    }
+
+    /// Trimmed form of the polynomial suitable for arithmetic.
+    pub fn to_polynomial(&self) -> Polynomial<'a> {
+        Polynomial::new(self.coeffs.clone(), self.field, &self.var)
+    }
+
+    /// Number of coefficients, including the trailing zeros.
+    pub fn len(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+}
+
+impl<'a> AsRef<[FieldElement<'a>]> for RawPolynomial<'a> {
+    fn as_ref(&self) -> &[FieldElement<'a>] {
+        &self.coeffs
+    }
+}
+
+impl<'a> cmp::PartialEq<RawPolynomial<'a>> for RawPolynomial<'a> {
+    fn eq(&self, other: &RawPolynomial<'a>) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RawPolynomial;
+    use crate::field::GaloisField;
+    use crate::polynomial::{Endianness, Polynomial};
+    use crate::utils;
+
+    static FIELD: GaloisField = crate::galois_field!();
+
+    #[test]
+    fn keeps_length_test() {
+        let coeffs = utils::nums_to_elements(vec![5, 0, 3221225472i64, 0, 0, 0], &FIELD);
+        let raw = RawPolynomial::new(coeffs.clone(), &FIELD, "x");
+        let poly = raw.to_polynomial();
+
+        assert_eq!(raw.len(), 6);
+        assert_eq!(raw.as_ref(), &coeffs[..]);
+        assert_eq!(poly.deg(), 2);
+        assert_eq!(RawPolynomial::with_len(&poly, 6), raw);
+
+        for endianness in [Endianness::Little, Endianness::Big] {
+            let bytes = raw.to_bytes(endianness);
+
+            assert_eq!(bytes.len(), 24);
+            assert_eq!(
+                RawPolynomial::try_from_bytes(&bytes, endianness, &FIELD, "x"),
+                Ok(raw.clone())
+            );
+        }
+
+        let zero = RawPolynomial::with_len(&Polynomial::new(vec![], &FIELD, "x"), 4);
+        assert_eq!(zero.len(), 4);
+        assert_eq!(zero.to_bytes(Endianness::Big), vec![0; 16]);
+        assert!(!zero.to_polynomial().non_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Impossible to fit polynomial of degree 2 into 2 coefficients.")]
+    fn with_len_too_short_test() {
+        let poly = Polynomial::new(utils::nums_to_elements(vec![1, 2, 3], &FIELD), &FIELD, "x");
+        RawPolynomial::with_len(&poly, 2);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::field::GaloisField;
+use crate::field_element::FieldElement;
+use crate::polynomial::Polynomial;
+
+use std::collections::BTreeMap;
+use std::{cmp, ops};
+
+/// Polynomial above some finite field `field` that stores only its non-zero terms.
+/// Suitable for polynomials of high degree with few terms, such as x^n or x^n - 1.
+/// # Arguments
+/// * `terms` - non-zero coefficients of the polynomial keyed by the powers of their terms
+/// * `field` - some finite field that provides polynomial coefficients
+/// * `var` - polynomial variable designation
+#[derive(Debug, Clone)]
+pub struct SparsePolynomial<'a> {
+    terms: BTreeMap<usize, FieldElement<'a>>,
+    field: &'a GaloisField,
+    var: String,
+}
+
+impl<'a> SparsePolynomial<'a> {
+    pub fn new(
+        terms: Vec<(usize, FieldElement<'a>)>,
+        field: &'a GaloisField,
+        var: &str,
+    ) -> SparsePolynomial<'a> {
+        let mut res = SparsePolynomial {
+            terms: BTreeMap::new(),
+            field,
+            var: var.to_string(),
+        };
+
+        for (pow, coef) in terms {
+            if coef.field as *const _ != field as *const _ {
+                panic!(
+                    "Not all coefficients of the constructing
+                polynomial are lay in the same field!"
+                );
+            }
+            res.add_term(pow, coef);
+        }
+
+        res
+    }
+
+    pub fn monomial(
+        deg: usize,
+        coef: FieldElement<'a>,
+        field: &'a GaloisField,
+    ) -> SparsePolynomial<'a> {
+        SparsePolynomial::new(vec![(deg, coef)], field, "x")
+    }
+
+    pub fn from_dense(poly: &Polynomial<'a>) -> SparsePolynomial<'a> {
+        SparsePolynomial::new(poly.terms().collect(), poly.field(), poly.var())
+    }
+
+    pub fn to_dense(&self) -> Polynomial<'a> {
+        let mut coeffs = vec![self.field.zero(); self.len()];
+        for (&pow, &coef) in self.terms.iter() {
+            coeffs[pow] = coef;
+        }
+
+        Polynomial::new(coeffs, self.field, &self.var)
+    }
+
+    pub fn deg(&self) -> i64 {
+        match self.terms.keys().next_back() {
+            Some(&pow) => pow as i64,
+            None => -1,
+        }
+    }
+
+    /// Iterates over non-zero terms as `(power, coefficient)` pairs
+    /// in ascending order of terms powers.
+    pub fn terms(&self) -> impl DoubleEndedIterator<Item = (usize, FieldElement<'a>)> + '_ {
+        self.terms.iter().map(|(&pow, &coef)| (pow, coef))
+    }
+
+    fn len(&self) -> usize {
+        self.terms.keys().next_back().map_or(0, |&pow| pow + 1)
+    }
+
+    fn add_term(&mut self, pow: usize, coef: FieldElement<'a>) {
+        let sum = match self.terms.get(&pow) {
+            Some(&cur) => cur + coef,
+            None => coef,
+        };
+
+        if sum.canonical_val() == self.field.zero {
+            self.terms.remove(&pow);
+        } else {
+            self.terms.insert(pow, sum);
+        }
+    }
+
+    fn check_bin_op_args(lhs: &SparsePolynomial, rhs: &SparsePolynomial) {
+        if lhs.field as *const _ != rhs.field as *const _ {
+            panic!(
+                "Polynomials are built over different fields: {} and {}!",
+                lhs.field, rhs.field
+            );
+        }
+
+        if lhs.var != rhs.var {
+            panic!("Polynomials have different variable names!");
+        }
+    }
+}
+
+impl<'a> cmp::PartialEq<SparsePolynomial<'a>> for SparsePolynomial<'a> {
+    fn eq(&self, other: &SparsePolynomial<'a>) -> bool {
+        self.terms == other.terms
+    }
+}
+
+impl<'a> ops::Add<SparsePolynomial<'a>> for SparsePolynomial<'a> {
+    type Output = SparsePolynomial<'a>;
+
+    fn add(mut self, rhs: SparsePolynomial<'a>) -> Self::Output {
+        SparsePolynomial::check_bin_op_args(&self, &rhs);
+
+        for (pow, coef) in rhs.terms {
+            self.add_term(pow, coef);
+        }
+
+        self
+    }
+}
+
+impl<'a> ops::Mul<SparsePolynomial<'a>> for SparsePolynomial<'a> {
+    type Output = SparsePolynomial<'a>;
+
+    fn mul(self, rhs: SparsePolynomial<'a>) -> Self::Output {
+        SparsePolynomial::check_bin_op_args(&self, &rhs);
+
+        let mut res = SparsePolynomial::new(vec![], self.field, &self.var);
+        for (&lhs_pow, &lhs_coef) in self.terms.iter() {
+            for (&rhs_pow, &rhs_coef) in rhs.terms.iter() {
+                res.add_term(lhs_pow + rhs_pow, lhs_coef * rhs_coef);
+            }
+        }
+
+        res
+    }
+}
+
+impl<'a> ops::Mul<&Polynomial<'a>> for &SparsePolynomial<'a> {
+    type Output = Polynomial<'a>;
+
+    /// Multiplies by a dense polynomial touching only the non-zero terms of `self`,
+    /// e.g. shifts `rhs` by x^n without building the dense x^n.
+    fn mul(self, rhs: &Polynomial<'a>) -> Self::Output {
+        if !std::ptr::eq(self.field, rhs.field()) {
+            panic!(
+                "Polynomials are built over different fields: {} and {}!",
+                self.field,
+                rhs.field()
+            );
+        }
+
+        if self.var != rhs.var() {
+            panic!("Polynomials have different variable names!");
+        }
+
+        let rhs_coeffs = rhs.as_ref();
+        if self.terms.is_empty() || rhs_coeffs.is_empty() {
+            return Polynomial::new(vec![], self.field, &self.var);
+        }
+
+        let mut coeffs = vec![self.field.zero(); self.len() + rhs_coeffs.len() - 1];
+        for (&pow, &coef) in self.terms.iter() {
+            for (i, &rhs_coef) in rhs_coeffs.iter().enumerate() {
+                coeffs[pow + i] += coef * rhs_coef;
+            }
+        }
+
+        Polynomial::new(coeffs, self.field, &self.var)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SparsePolynomial;
+    use crate::field::GaloisField;
+    use crate::polynomial::Polynomial;
+    use crate::utils;
+
+    use itertools::izip;
+
+    static FIELD: GaloisField = crate::galois_field!();
+
+    #[test]
+    fn dense_conversion_test() {
+        let dense_data = [
+            vec![],
+            vec![12],
+            vec![0, 0, 0, 1],
+            vec![17, 0, -3, 0, 0, 0, 0, 2821],
+        ];
+
+        for raw in dense_data {
+            let dense = Polynomial::new(utils::nums_to_elements(raw, &FIELD), &FIELD, "x");
+            let sparse = SparsePolynomial::from_dense(&dense);
+
+            assert_eq!(sparse.deg(), dense.deg());
+            assert_eq!(sparse.to_dense(), dense);
+            assert!(sparse.terms().eq(dense.terms()));
+        }
+
+        let monomial = SparsePolynomial::monomial(1000, FIELD.new_element(5), &FIELD);
+        assert_eq!(monomial.terms().count(), 1);
+        assert_eq!(
+            monomial.to_dense(),
+            Polynomial::monomial(1000, FIELD.new_element(5), &FIELD)
+        );
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let lhs_data = [
+            vec![16, 10, 13],
+            vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            vec![-1, 0, 0, 0, 0, 1],
+            vec![],
+        ];
+        let rhs_data = [
+            vec![12, 18, 20, 15],
+            vec![-1, 0, 0, 0, 0, 0, 0, 0, 0, -1],
+            vec![1, 0, 0, 0, 0, 1],
+            vec![3, 4],
+        ];
+
+        for (lhs_raw, rhs_raw) in izip!(lhs_data, rhs_data) {
+            let lhs = Polynomial::new(utils::nums_to_elements(lhs_raw, &FIELD), &FIELD, "x");
+            let rhs = Polynomial::new(utils::nums_to_elements(rhs_raw, &FIELD), &FIELD, "x");
+            let sparse_lhs = SparsePolynomial::from_dense(&lhs);
+            let sparse_rhs = SparsePolynomial::from_dense(&rhs);
+
+            assert_eq!(
+                (sparse_lhs.clone() + sparse_rhs.clone()).to_dense(),
+                lhs.clone() + rhs.clone()
+            );
+            assert_eq!(&sparse_lhs * &rhs, lhs.clone() * rhs.clone());
+            assert_eq!((sparse_lhs * sparse_rhs).to_dense(), lhs * rhs);
+        }
+    }
+}
@@ -32,6 +32,175 @@ pub fn zip_longest_with_op<'a>(
         .collect()
 }
 
-pub fn nums_to_elements(nums: Vec<i128>, field: &GaloisField) -> Vec<FieldElement> {
-    nums.into_iter().map(|num| field.new_element(num)).collect()
+/// Maps integers of any type convertible into `i128` to the elements of the `field`.
+pub fn nums_to_elements<T: Into<i128>>(
+    nums: impl IntoIterator<Item = T>,
+    field: &GaloisField,
+) -> Vec<FieldElement> {
+    nums.into_iter()
+        .map(|num| field.new_element(num.into()))
+        .collect()
+}
+
+/// Reverses the order of the lowest `bits` bits of `index`.
+pub fn bit_reverse(index: usize, bits: u32) -> usize {
+    if bits == 0 {
+        0
+    } else {
+        index.reverse_bits() >> (usize::BITS - bits)
+    }
+}
+
+/// Permutes elements in place so that the element at index `i` is moved
+/// to index `bit_reverse(i, log2(len))`. Length should be a power of 2.
+pub fn bit_reverse_permute(elements: &mut [FieldElement]) {
+    if !elements.len().is_power_of_two() {
+        panic!("Bit-reversal permutation requires length that is a power of 2.");
+    }
+
+    let bits = elements.len().trailing_zeros();
+    for i in 0..elements.len() {
+        let j = bit_reverse(i, bits);
+        if i < j {
+            elements.swap(i, j);
+        }
+    }
+}
+
+/// Number of positions at which the vectors of symbols differ.
+pub fn hamming_distance(lhs: &[FieldElement], rhs: &[FieldElement]) -> usize {
+    if lhs.len() != rhs.len() {
+        panic!(
+            "Impossible to calculate Hamming distance between vectors of different lengths: {} and {}.",
+            lhs.len(),
+            rhs.len()
+        );
+    }
+
+    lhs.iter().zip(rhs.iter()).filter(|(l, r)| l != r).count()
+}
+
+/// Number of non-zero symbols of the vector.
+pub fn hamming_weight(elements: &[FieldElement]) -> usize {
+    elements
+        .iter()
+        .filter(|el| el.canonical_val() != el.field.zero)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bit_reverse, bit_reverse_permute, hamming_distance, hamming_weight, nums_to_elements,
+    };
+    use crate::field::GaloisField;
+
+    use itertools::izip;
+
+    static FIELD: GaloisField = crate::galois_field!();
+
+    #[test]
+    fn nums_to_elements_test() {
+        let bytes: &[u8] = &[0, 1, 255];
+        let ints: &[i32] = &[-1, 0, i32::MAX, i32::MIN];
+        let res_data = [
+            vec![FIELD.zero(), FIELD.one(), FIELD.new_element(255)],
+            vec![
+                FIELD.new_element(-1),
+                FIELD.zero(),
+                FIELD.new_element(2147483647),
+                FIELD.new_element(-2147483648),
+            ],
+        ];
+
+        assert_eq!(nums_to_elements(bytes.iter().copied(), &FIELD), res_data[0]);
+        assert_eq!(nums_to_elements(ints.to_vec(), &FIELD), res_data[1]);
+        assert_eq!(
+            nums_to_elements(vec![-1i128, 3221225473], &FIELD),
+            vec![FIELD.new_element(3221225472), FIELD.zero()]
+        );
+    }
+
+    #[test]
+    fn bit_reverse_test() {
+        let test_data = [
+            (0, 0, 0),
+            (0, 3, 0),
+            (1, 1, 1),
+            (1, 3, 4),
+            (3, 3, 6),
+            (6, 3, 3),
+            (5, 4, 10),
+            (1, 64, 1 << 63),
+            (0b1011, 10, 0b1101000000),
+        ];
+
+        for (index, bits, res) in test_data {
+            assert_eq!(bit_reverse(index, bits), res);
+        }
+    }
+
+    #[test]
+    fn bit_reverse_permute_test() {
+        let mut elements = nums_to_elements(0..8, &FIELD);
+        bit_reverse_permute(&mut elements);
+        assert_eq!(
+            elements,
+            nums_to_elements(vec![0, 4, 2, 6, 1, 5, 3, 7], &FIELD)
+        );
+
+        let mut single = nums_to_elements(vec![42], &FIELD);
+        bit_reverse_permute(&mut single);
+        assert_eq!(single, nums_to_elements(vec![42], &FIELD));
+    }
+
+    #[test]
+    #[should_panic(expected = "requires length that is a power of 2")]
+    fn bit_reverse_permute_invalid_len_test() {
+        bit_reverse_permute(&mut nums_to_elements(vec![1, 2, 3], &FIELD));
+    }
+
+    #[test]
+    fn hamming_test() {
+        let lhs_data = [
+            vec![],
+            vec![1, 2, 3, 4],
+            vec![1, 2, 3, 4],
+            vec![0, 5, 0, -1, 7],
+        ];
+        let rhs_data = [
+            vec![],
+            vec![1, 2, 3, 4],
+            vec![2, 3, 4, 5],
+            vec![0, 5, 1, 3221225472i64, 8],
+        ];
+        let distance_data = [0, 0, 4, 2];
+        let weight_data = [0, 4, 4, 3];
+
+        for (lhs_raw, rhs_raw, distance, weight) in
+            izip!(lhs_data, rhs_data, distance_data, weight_data)
+        {
+            let lhs = nums_to_elements(lhs_raw, &FIELD);
+            let rhs = nums_to_elements(rhs_raw, &FIELD);
+
+            assert_eq!(hamming_distance(&lhs, &rhs), distance);
+            assert_eq!(hamming_distance(&rhs, &lhs), distance);
+            assert_eq!(hamming_distance(&lhs, &lhs), 0);
+            assert_eq!(hamming_weight(&lhs), weight);
+        }
+
+        assert_eq!(
+            hamming_weight(&nums_to_elements(vec![0, 0, 3221225473i64], &FIELD)),
+            0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "vectors of different lengths: 2 and 3.")]
+    fn hamming_distance_different_lengths_test() {
+        hamming_distance(
+            &nums_to_elements(vec![1, 2], &FIELD),
+            &nums_to_elements(vec![1, 2, 3], &FIELD),
+        );
+    }
 }
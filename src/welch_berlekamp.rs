@@ -0,0 +1,251 @@
+use crate::field::GaloisField;
+use crate::field_element::FieldElement;
+use crate::polynomial::Polynomial;
+
+use itertools::izip;
+use std::collections::HashSet;
+
+/// Recovers polynomial f of degree less than `k` from its evaluations `values` at `points`,
+/// some of which may be corrupted, using Welch–Berlekamp algorithm. Up to (n - k) / 2 errors
+/// are corrected, where n is the number of points. Finds error locator E (monic, of degree
+/// (n - k) / 2) and numerator N = E * f from the linear system N(x_i) = y_i * E(x_i)
+/// and returns f = N / E, or `None` if the evaluations are too corrupted to be decoded.
+pub fn welch_berlekamp_decode<'a>(
+    points: &[FieldElement<'a>],
+    values: &[FieldElement<'a>],
+    k: usize,
+) -> Option<Polynomial<'a>> {
+    let field = check_args(points, values, k);
+    let n = points.len();
+    let max_errors = (n - k) / 2;
+
+    // Unknowns are e_0..e_{max_errors - 1} (the leading coefficient of E is 1)
+    // followed by n_0..n_{max_errors + k - 1}, the last column is the right-hand side.
+    let rows = points
+        .iter()
+        .zip(values.iter())
+        .map(|(&x, &y)| {
+            let powers = field.powers_of(x, max_errors + k).collect::<Vec<_>>();
+            let locator_part = powers[..max_errors].iter().map(|&pow| -(y * pow));
+            let rhs = y * x.pow(max_errors as u32);
+
+            locator_part
+                .chain(powers.iter().copied())
+                .chain(Some(rhs))
+                .collect()
+        })
+        .collect();
+
+    let solution = solve(rows, field)?;
+    let mut locator_coeffs = solution[..max_errors].to_vec();
+    locator_coeffs.push(field.one());
+    let locator = Polynomial::new(locator_coeffs, field, "x");
+    let numerator = Polynomial::new(solution[max_errors..].to_vec(), field, "x");
+
+    let (poly, rem) = numerator.div_rem(&locator);
+    let mismatches = points
+        .iter()
+        .zip(values.iter())
+        .filter(|&(&x, &y)| poly.evaluate(x) != y)
+        .count();
+
+    if rem.non_empty() || poly.deg() >= k as i64 || mismatches > max_errors {
+        None
+    } else {
+        Some(poly)
+    }
+}
+
+/// Same as `welch_berlekamp_decode`, but every evaluation with reliability less than
+/// `threshold` is treated as an erasure and left out of decoding. Dropping e erasures
+/// leaves (n - e - k) / 2 correctable errors among the rest of evaluations, so erasing
+/// the least reliable values can recover f where blind decoding fails.
+pub fn welch_berlekamp_decode_with_reliability<'a>(
+    points: &[FieldElement<'a>],
+    values: &[FieldElement<'a>],
+    reliability: &[u8],
+    threshold: u8,
+    k: usize,
+) -> Option<Polynomial<'a>> {
+    if reliability.len() != points.len() {
+        panic!(
+            "Impossible to match {} reliability values with {} evaluations.",
+            reliability.len(),
+            points.len()
+        );
+    }
+    check_args(points, values, k);
+
+    let (points, values): (Vec<_>, Vec<_>) = izip!(points, values, reliability)
+        .filter(|&(_, _, &rel)| rel >= threshold)
+        .map(|(&x, &y, _)| (x, y))
+        .unzip();
+
+    if points.len() < k {
+        None
+    } else {
+        welch_berlekamp_decode(&points, &values, k)
+    }
+}
+
+fn check_args<'a>(
+    points: &[FieldElement<'a>],
+    values: &[FieldElement<'a>],
+    k: usize,
+) -> &'a GaloisField {
+    if points.len() != values.len() {
+        panic!("Impossible to establish a one-to-one correspondence between the definition and value areas.");
+    }
+
+    if k == 0 || points.len() < k {
+        panic!(
+            "Impossible to decode polynomial of degree less than {} from {} points.",
+            k,
+            points.len()
+        );
+    }
+
+    let field = points[0].field;
+    let mut distinct = HashSet::with_capacity(points.len());
+    for el in points.iter().chain(values.iter()) {
+        if !std::ptr::eq(el.field, field) {
+            panic!("Not all elements are lay in the same field.");
+        }
+    }
+    if !points.iter().all(|el| distinct.insert(el.canonical_val())) {
+        panic!("Impossible to decode: evaluation points are not distinct.");
+    }
+
+    field
+}
+
+/// Solves linear system given by its augmented matrix with Gauss–Jordan elimination.
+/// Free variables of the underdetermined system are set to zero,
+/// returns `None` if the system is inconsistent.
+fn solve<'a>(
+    mut rows: Vec<Vec<FieldElement<'a>>>,
+    field: &'a GaloisField,
+) -> Option<Vec<FieldElement<'a>>> {
+    let cols = rows[0].len() - 1;
+    let mut pivots = Vec::with_capacity(cols);
+
+    for col in 0..cols {
+        let cur = pivots.len();
+        let pivot = match (cur..rows.len()).find(|&i| rows[i][col] != field.zero()) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        rows.swap(cur, pivot);
+
+        let scale = rows[cur][col].inverse();
+        for el in rows[cur].iter_mut() {
+            *el *= scale;
+        }
+
+        for i in 0..rows.len() {
+            let factor = rows[i][col];
+            if i != cur && factor != field.zero() {
+                for j in col..=cols {
+                    let sub = factor * rows[cur][j];
+                    rows[i][j] -= sub;
+                }
+            }
+        }
+
+        pivots.push(col);
+    }
+
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| row[cols] != field.zero())
+    {
+        return None;
+    }
+
+    let mut solution = vec![field.zero(); cols];
+    for (row, &col) in pivots.iter().enumerate() {
+        solution[col] = rows[row][cols];
+    }
+
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{welch_berlekamp_decode, welch_berlekamp_decode_with_reliability};
+    use crate::field::GaloisField;
+    use crate::polynomial::Polynomial;
+    use crate::utils;
+
+    use itertools::izip;
+
+    static FIELD: GaloisField = crate::galois_field!();
+
+    #[test]
+    fn decode_test() {
+        let poly_data = [
+            vec![5, 8, 13],
+            vec![-1712893553, 2373853268i64, 0, 157],
+            vec![42],
+            vec![1, 2, 3, 4, 5, 6, 7, 8],
+        ];
+        let n_data = [9, 10, 5, 8];
+        let errors_data = [vec![0, 4, 8], vec![1, 6, 7], vec![0, 3], vec![]];
+
+        for (poly_raw, n, errors) in izip!(poly_data, n_data, errors_data) {
+            let k = poly_raw.len();
+            let poly = Polynomial::new(utils::nums_to_elements(poly_raw, &FIELD), &FIELD, "x");
+            let points = utils::nums_to_elements(1..=n, &FIELD);
+            let mut values = points.iter().map(|&x| poly.evaluate(x)).collect::<Vec<_>>();
+
+            for i in errors {
+                values[i] += FIELD.new_element(i as i128 + 1);
+            }
+
+            assert_eq!(welch_berlekamp_decode(&points, &values, k), Some(poly));
+        }
+    }
+
+    #[test]
+    fn decode_too_many_errors_test() {
+        let poly = Polynomial::new(utils::nums_to_elements(vec![5, 8, 13], &FIELD), &FIELD, "x");
+        let points = utils::nums_to_elements(1..=7, &FIELD);
+        let mut values = points.iter().map(|&x| poly.evaluate(x)).collect::<Vec<_>>();
+
+        for (i, val) in [(0, 17), (1, -3), (2, 1039791324), (3, 4)] {
+            values[i] = FIELD.new_element(val);
+        }
+
+        assert_eq!(welch_berlekamp_decode(&points, &values, 3), None);
+    }
+
+    #[test]
+    fn decode_with_reliability_test() {
+        let poly = Polynomial::new(utils::nums_to_elements(vec![5, 8, 13], &FIELD), &FIELD, "x");
+        let points = utils::nums_to_elements(1..=9, &FIELD);
+        let mut values = points.iter().map(|&x| poly.evaluate(x)).collect::<Vec<_>>();
+        let mut reliability = vec![200u8; 9];
+
+        for (i, rel) in [(0, 3), (2, 10), (5, 7), (7, 1), (8, 250)] {
+            values[i] += FIELD.new_element(i as i128 + 1);
+            reliability[i] = rel;
+        }
+
+        assert_eq!(welch_berlekamp_decode(&points, &values, 3), None);
+        assert_eq!(
+            welch_berlekamp_decode_with_reliability(&points, &values, &reliability, 50, 3),
+            Some(poly.clone())
+        );
+        assert_eq!(
+            welch_berlekamp_decode_with_reliability(&points, &values, &reliability, 255, 3),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Impossible to match 2 reliability values with 3 evaluations.")]
+    fn decode_with_reliability_mismatched_lengths_test() {
+        let points = utils::nums_to_elements(1..=3, &FIELD);
+        welch_berlekamp_decode_with_reliability(&points, &points, &[1, 2], 1, 1);
+    }
+}